@@ -29,6 +29,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     println!();
 
+    // Example 3b: Get typed title ratings
+    println!("3b. Getting typed ratings for tt0111161:");
+    match client.get_title_ratings_typed("tt0111161").await {
+        Ok(ratings) => {
+            println!(
+                "   {} ({:?}): rated {:?} by {:?} users",
+                ratings.title, ratings.year, ratings.rating, ratings.rating_count
+            );
+        }
+        Err(e) => println!("   Error: {}", e),
+    }
+    println!();
+
     // Example 4: Search for a title
     println!("4. Searching for 'The Dark Knight':");
     match client.search("The Dark Knight").await {
@@ -41,17 +54,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 5: Search for a person
     println!("5. Searching for 'Christian Bale':");
-    match client.search("Christian Bale").await {
-        Ok(results) => {
-            if let Some(d) = results.get("d") {
-                if let Some(arr) = d.as_array() {
-                    println!("   Found {} results", arr.len());
-                    for (i, item) in arr.iter().take(5).enumerate() {
-                        if let Some(name) = item.get("l").and_then(|l| l.as_str()) {
-                            println!("   {}: {}", i + 1, name);
-                        }
-                    }
-                }
+    match client.search_names("Christian Bale").await {
+        Ok(names) => {
+            println!("   Found {} results", names.len());
+            for (i, name) in names.iter().take(5).enumerate() {
+                println!("   {}: {} ({})", i + 1, name.name, name.id);
             }
         }
         Err(e) => println!("   Error: {}", e),
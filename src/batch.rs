@@ -0,0 +1,231 @@
+//! Fetch many titles or names with bounded concurrency
+
+use crate::client::ImdbClient;
+use crate::constants::BATCH_CONCURRENCY;
+use crate::error::Result;
+use crate::id::{ImdbId, NameId, TitleId};
+use crate::models::{Name, Title};
+use futures::stream::{self, Stream, StreamExt};
+use std::collections::HashSet;
+use std::fmt;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// Progress of a batch, reported after each ID completes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// IDs fetched so far, successfully or not
+    pub completed: usize,
+    /// IDs whose fetch failed so far
+    pub failed: usize,
+    /// Distinct IDs in the batch
+    pub total: usize,
+}
+
+/// Options for [`ImdbClient::get_titles_batch`] and [`ImdbClient::get_names_batch`]
+#[derive(Clone)]
+pub struct BatchOptions {
+    concurrency: usize,
+    on_progress: Option<Arc<dyn Fn(BatchProgress) + Send + Sync>>,
+}
+
+impl BatchOptions {
+    /// Default options: [`BATCH_CONCURRENCY`] requests at once, no progress callback
+    pub fn new() -> Self {
+        Self {
+            concurrency: BATCH_CONCURRENCY,
+            on_progress: None,
+        }
+    }
+
+    /// Fetch at most `concurrency` IDs at once
+    ///
+    /// The client's rate limit still applies on top of this.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Call `callback` after each ID completes
+    pub fn on_progress(mut self, callback: impl Fn(BatchProgress) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for BatchOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchOptions")
+            .field("concurrency", &self.concurrency)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+impl ImdbClient {
+    /// Fetch many titles, yielding each as soon as it completes
+    ///
+    /// Duplicate IDs are fetched once. The stream owns a clone of the client,
+    /// so it can be moved into a spawned task.
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use imdb_api::{BatchOptions, ImdbClient, TitleId};
+    ///
+    /// # async fn run() -> imdb_api::Result<()> {
+    /// let client = ImdbClient::new();
+    /// let ids: Vec<TitleId> = vec!["tt0111161".parse()?, "tt0068646".parse()?];
+    /// let options = BatchOptions::new()
+    ///     .concurrency(8)
+    ///     .on_progress(|p| eprintln!("{}/{}", p.completed, p.total));
+    ///
+    /// let mut titles = client.get_titles_batch(ids, options);
+    /// while let Some((id, title)) = titles.next().await {
+    ///     match title {
+    ///         Ok(title) => println!("{}: {}", id, title.title),
+    ///         Err(e) => eprintln!("{}: {}", id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_titles_batch(
+        &self,
+        ids: impl IntoIterator<Item = TitleId>,
+        options: BatchOptions,
+    ) -> impl Stream<Item = (TitleId, Result<Title>)> + Send + 'static {
+        self.batch(ids, options, |client, id| async move {
+            client.get_title_typed(&id).await
+        })
+    }
+
+    /// Fetch many names, yielding each as soon as it completes
+    ///
+    /// See [`get_titles_batch`](Self::get_titles_batch).
+    pub fn get_names_batch(
+        &self,
+        ids: impl IntoIterator<Item = NameId>,
+        options: BatchOptions,
+    ) -> impl Stream<Item = (NameId, Result<Name>)> + Send + 'static {
+        self.batch(ids, options, |client, id| async move {
+            client.get_name_typed(&id).await
+        })
+    }
+
+    fn batch<Id, T, F, Fut>(
+        &self,
+        ids: impl IntoIterator<Item = Id>,
+        options: BatchOptions,
+        fetch: F,
+    ) -> impl Stream<Item = (Id, Result<T>)> + Send + 'static
+    where
+        Id: ImdbId + Hash + Eq + Send + 'static,
+        T: Send + 'static,
+        F: Fn(ImdbClient, Id) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        let mut seen = HashSet::new();
+        let ids: Vec<Id> = ids.into_iter().filter(|id| seen.insert(id.clone())).collect();
+
+        let mut progress = BatchProgress {
+            completed: 0,
+            failed: 0,
+            total: ids.len(),
+        };
+        let client = self.clone();
+
+        stream::iter(ids)
+            .map(move |id| {
+                let result = fetch(client.clone(), id.clone());
+                async move { (id, result.await) }
+            })
+            .buffer_unordered(options.concurrency)
+            .map(move |(id, result)| {
+                progress.completed += 1;
+                if result.is_err() {
+                    progress.failed += 1;
+                }
+                if let Some(callback) = &options.on_progress {
+                    callback(progress);
+                }
+                (id, result)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rate_limit::RateLimit;
+    use crate::retry::RetryPolicy;
+    use crate::transport::{HttpResponse, StubTransport};
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_batch_deduplicates_and_reports_progress() {
+        // Knows only The Shawshank Redemption
+        let transport = Arc::new(StubTransport::new(|request| match request.url.contains("tt0111161") {
+            true => HttpResponse::new(200, json!({ "resource": { "title": "The Shawshank Redemption" } }).to_string()),
+            false => HttpResponse::new(404, "not found"),
+        }));
+        let client = ImdbClient::builder()
+            .base_uri("http://imdb.test")
+            .rate_limit(RateLimit::unlimited())
+            .retry_policy(RetryPolicy::none())
+            .shared_transport(transport.clone())
+            .build()
+            .unwrap();
+        let ids: Vec<TitleId> = ["tt0111161", "tt0068646", "tt0111161"]
+            .iter()
+            .map(|id| id.parse().unwrap())
+            .collect();
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let options = BatchOptions::new()
+            .concurrency(2)
+            .on_progress(move |p| sink.lock().unwrap().push(p));
+
+        let mut results: Vec<_> = client.get_titles_batch(ids, options).collect().await;
+        results.sort_by_key(|(id, _)| id.to_string());
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0.to_string(), "tt0068646");
+        assert!(matches!(results[0].1, Err(crate::ImdbApiError::NotFound { status: 404, .. })));
+        assert_eq!(results[1].1.as_ref().unwrap().title, "The Shawshank Redemption");
+
+        let mut requested: Vec<_> = transport
+            .requests()
+            .into_iter()
+            .filter(|r| r.url.contains("/title/"))
+            .map(|r| r.url)
+            .collect();
+        requested.sort();
+        assert_eq!(
+            requested,
+            [
+                "http://imdb.test/title/tt0068646/auxiliary",
+                "http://imdb.test/title/tt0111161/auxiliary"
+            ]
+        );
+
+        let reports = reports.lock().unwrap();
+        let completed: Vec<_> = reports.iter().map(|p| (p.completed, p.total)).collect();
+        assert_eq!(completed, [(1, 2), (2, 2)]);
+        assert_eq!(
+            reports.last(),
+            Some(&BatchProgress {
+                completed: 2,
+                failed: 1,
+                total: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Synchronous client, enabled by the `blocking` feature
+//!
+//! [`ImdbClient`] wraps the async [`crate::ImdbClient`] and drives it on an
+//! internal single-threaded Tokio runtime, so it can be used from code that
+//! has no runtime of its own. It must not be used from within an async
+//! context, where blocking would panic.
+//!
+//! ```no_run
+//! use imdb_api::blocking::ImdbClient;
+//!
+//! let client = ImdbClient::new();
+//! let title = client.get_title_typed("tt0111161")?;
+//! println!("{}", title.title);
+//! # Ok::<(), imdb_api::ImdbApiError>(())
+//! ```
+
+use crate::batch::BatchOptions;
+use crate::builder::ImdbClientBuilder;
+use crate::endpoint::Endpoint;
+use crate::error::Result;
+use crate::full::{FullTitle, TitlePart};
+use crate::id::{IntoId, NameId, TitleId};
+use crate::models::*;
+use crate::release::ParsedRelease;
+use crate::resolve::TitleMatch;
+use futures::stream::{BoxStream, StreamExt};
+use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
+use tokio::runtime::{Builder, Runtime};
+
+/// Blocking counterpart of [`crate::ImdbClient`], with the same methods
+///
+/// Cloning is cheap; clones share the runtime, credentials and cache.
+#[derive(Debug, Clone)]
+pub struct ImdbClient {
+    inner: crate::ImdbClient,
+    runtime: Arc<Runtime>,
+}
+
+impl ImdbClient {
+    /// Create a new blocking client with the default settings
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client or the runtime cannot be initialized. Use
+    /// [`ImdbClientBuilder::build_blocking`] to handle the error.
+    pub fn new() -> Self {
+        Self::builder()
+            .build_blocking()
+            .expect("failed to initialize the blocking client")
+    }
+
+    /// Create a builder; finish it with [`ImdbClientBuilder::build_blocking`]
+    pub fn builder() -> ImdbClientBuilder {
+        ImdbClientBuilder::new()
+    }
+
+    /// Wrap an async client, sharing its credentials and cache
+    pub fn from_async(inner: crate::ImdbClient) -> Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this one drives
+    pub fn as_async(&self) -> &crate::ImdbClient {
+        &self.inner
+    }
+
+    pub(crate) fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Check if a title exists on IMDB
+    pub fn title_exists(&self, imdb_id: impl IntoId<TitleId>) -> Result<bool> {
+        self.block_on(self.inner.title_exists(imdb_id))
+    }
+
+    /// Search for titles or names on IMDB
+    pub fn search(&self, query: &str) -> Result<Value> {
+        self.block_on(self.inner.search(query))
+    }
+
+    /// Search for titles or names on IMDB, decoding the suggestions
+    pub fn search_results(&self, query: &str) -> Result<Vec<SearchResult>> {
+        self.block_on(self.inner.search_results(query))
+    }
+
+    /// Search for titles, keeping only the given kinds (all kinds if empty)
+    pub fn search_titles(&self, query: &str, kinds: &[TitleKind]) -> Result<Vec<TitleSuggestion>> {
+        self.block_on(self.inner.search_titles(query, kinds))
+    }
+
+    /// Search for people
+    pub fn search_names(&self, query: &str) -> Result<Vec<NameSuggestion>> {
+        self.block_on(self.inner.search_names(query))
+    }
+
+    /// Get the raw resource of any endpoint
+    pub fn get(&self, endpoint: Endpoint, imdb_id: impl AsRef<str>) -> Result<Value> {
+        self.block_on(self.inner.get(endpoint, imdb_id))
+    }
+
+    /// Get title information, or `None` if the title does not exist
+    pub fn get_title_opt(&self, imdb_id: impl IntoId<TitleId>) -> Result<Option<Title>> {
+        self.block_on(self.inner.get_title_opt(imdb_id))
+    }
+
+    /// Get name information, or `None` if the person does not exist
+    pub fn get_name_opt(&self, imdb_id: impl IntoId<NameId>) -> Result<Option<Name>> {
+        self.block_on(self.inner.get_name_opt(imdb_id))
+    }
+
+    /// Get one season of a series
+    pub fn get_season(&self, series_id: impl IntoId<TitleId>, season: u32) -> Result<Season> {
+        self.block_on(self.inner.get_season(series_id, season))
+    }
+
+    /// Get one episode of a series by season and episode number
+    pub fn get_episode(
+        &self,
+        series_id: impl IntoId<TitleId>,
+        season: u32,
+        episode: u32,
+    ) -> Result<Episode> {
+        self.block_on(self.inner.get_episode(series_id, season, episode))
+    }
+
+    /// Get the episode following `episode_id`, or `None` for the last episode
+    pub fn next_episode(&self, episode_id: impl IntoId<TitleId>) -> Result<Option<Episode>> {
+        self.block_on(self.inner.next_episode(episode_id))
+    }
+
+    /// Get the episode preceding `episode_id`, or `None` for the first episode
+    pub fn previous_episode(&self, episode_id: impl IntoId<TitleId>) -> Result<Option<Episode>> {
+        self.block_on(self.inner.previous_episode(episode_id))
+    }
+
+    /// Get the most recent episode of a series that has already aired
+    pub fn latest_aired_episode(&self, series_id: impl IntoId<TitleId>) -> Result<Option<Episode>> {
+        self.block_on(self.inner.latest_aired_episode(series_id))
+    }
+
+    /// Fetch the requested sections of a title concurrently
+    pub fn get_title_full(
+        &self,
+        imdb_id: impl IntoId<TitleId>,
+        parts: impl IntoIterator<Item = TitlePart>,
+    ) -> Result<FullTitle> {
+        self.block_on(self.inner.get_title_full(imdb_id, parts))
+    }
+
+    /// Like [`get_title_full`](Self::get_title_full), with at most
+    /// `concurrency` sections fetched at once
+    pub fn get_title_full_with_concurrency(
+        &self,
+        imdb_id: impl IntoId<TitleId>,
+        parts: impl IntoIterator<Item = TitlePart>,
+        concurrency: usize,
+    ) -> Result<FullTitle> {
+        self.block_on(self.inner.get_title_full_with_concurrency(imdb_id, parts, concurrency))
+    }
+
+    /// Resolve a free-form title to ranked IMDB title candidates
+    pub fn resolve_title(
+        &self,
+        query: &str,
+        year: Option<u32>,
+        kind: Option<TitleKind>,
+    ) -> Result<Vec<TitleMatch>> {
+        self.block_on(self.inner.resolve_title(query, year, kind))
+    }
+
+    /// Resolve a parsed release name, such as a media filename
+    pub fn resolve_release(&self, release: &ParsedRelease) -> Result<Vec<TitleMatch>> {
+        self.block_on(self.inner.resolve_release(release))
+    }
+
+    /// Fetch many titles, yielding each as soon as it completes
+    pub fn get_titles_batch(
+        &self,
+        ids: impl IntoIterator<Item = TitleId>,
+        options: BatchOptions,
+    ) -> BatchIter<(TitleId, Result<Title>)> {
+        self.batch_iter(self.inner.get_titles_batch(ids, options).boxed())
+    }
+
+    /// Fetch many names, yielding each as soon as it completes
+    pub fn get_names_batch(
+        &self,
+        ids: impl IntoIterator<Item = NameId>,
+        options: BatchOptions,
+    ) -> BatchIter<(NameId, Result<Name>)> {
+        self.batch_iter(self.inner.get_names_batch(ids, options).boxed())
+    }
+
+    fn batch_iter<T>(&self, stream: BoxStream<'static, T>) -> BatchIter<T> {
+        BatchIter {
+            stream,
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+impl Default for ImdbClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Blocking iterator over the results of a batch
+///
+/// Requests only make progress while the iterator is being advanced.
+pub struct BatchIter<T> {
+    stream: BoxStream<'static, T>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Iterator for BatchIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+impl<T> fmt::Debug for BatchIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchIter").finish_non_exhaustive()
+    }
+}
+
+impl ImdbClientBuilder {
+    /// Build a blocking client
+    pub fn build_blocking(self) -> Result<ImdbClient> {
+        ImdbClient::from_async(self.build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ImdbApiError;
+
+    #[test]
+    fn test_blocking_client_without_runtime() {
+        let client = ImdbClient::new();
+        assert!(matches!(client.get_title("not-an-id"), Err(ImdbApiError::InvalidImdbId(_))));
+        assert!(!client.title_exists("nm0000001").unwrap());
+        assert!(client.get_titles_batch([], BatchOptions::new()).next().is_none());
+    }
+}
@@ -0,0 +1,306 @@
+use crate::cache::{Cache, CachePolicy};
+use crate::client::ImdbClient;
+use crate::constants::*;
+use crate::error::Result;
+use crate::rate_limit::RateLimit;
+use crate::retry::RetryPolicy;
+use crate::signer::{Clock, Signer};
+#[cfg(feature = "reqwest")]
+use crate::transport::ReqwestTransport;
+use crate::transport::{HttpTransport, Sleep, TokioSleep};
+#[cfg(feature = "reqwest")]
+use reqwest::Client as HttpClient;
+use std::sync::Arc;
+#[cfg(feature = "reqwest")]
+use std::time::Duration;
+
+/// Endpoints and request settings used by an [`ImdbClient`]
+#[derive(Debug, Clone)]
+pub(crate) struct ClientConfig {
+    pub base_uri: String,
+    pub search_base_uri: String,
+    pub web_base_uri: String,
+    pub user_agent: String,
+    pub app_key: String,
+    pub locale: String,
+    pub retry_policy: RetryPolicy,
+    pub rate_limit: RateLimit,
+    pub cache: Option<Arc<dyn Cache>>,
+    pub cache_policy: CachePolicy,
+    pub signer: Signer,
+    pub sleep: Arc<dyn Sleep>,
+}
+
+/// Builder for [`ImdbClient`]
+///
+/// # Example
+///
+/// ```no_run
+/// use imdb_api::ImdbClient;
+/// use std::time::Duration;
+///
+/// # #[cfg(feature = "reqwest")]
+/// let client = ImdbClient::builder()
+///     .base_uri("http://localhost:8080")
+///     .locale("fr_FR")
+///     .connect_timeout(Duration::from_secs(5))
+///     .build()?;
+/// # Ok::<(), imdb_api::ImdbApiError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImdbClientBuilder {
+    config: ClientConfig,
+    transport: Option<Arc<dyn HttpTransport>>,
+    #[cfg(feature = "reqwest")]
+    http_client: Option<HttpClient>,
+    #[cfg(feature = "reqwest")]
+    connect_timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    read_timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    timeout: Option<Duration>,
+}
+
+impl ImdbClientBuilder {
+    /// Create a builder with the default IMDB endpoints and settings
+    pub fn new() -> Self {
+        Self {
+            config: ClientConfig {
+                base_uri: BASE_URI.to_string(),
+                search_base_uri: SEARCH_BASE_URI.to_string(),
+                web_base_uri: WEB_BASE_URI.to_string(),
+                user_agent: USER_AGENT.to_string(),
+                app_key: APP_KEY.to_string(),
+                locale: DEFAULT_LOCALE.to_string(),
+                retry_policy: RetryPolicy::default(),
+                rate_limit: RateLimit::default(),
+                cache: None,
+                cache_policy: CachePolicy::default(),
+                signer: Signer::new(),
+                sleep: Arc::new(TokioSleep),
+            },
+            transport: None,
+            #[cfg(feature = "reqwest")]
+            http_client: None,
+            #[cfg(feature = "reqwest")]
+            connect_timeout: None,
+            #[cfg(feature = "reqwest")]
+            read_timeout: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+        }
+    }
+
+    /// Base URI of the IMDB JSON API (default `https://api.imdbws.com`)
+    pub fn base_uri(mut self, uri: impl Into<String>) -> Self {
+        self.config.base_uri = trim_uri(uri.into());
+        self
+    }
+
+    /// Base URI of the search suggestion service (default `https://v2.sg.media-imdb.com`)
+    pub fn search_base_uri(mut self, uri: impl Into<String>) -> Self {
+        self.config.search_base_uri = trim_uri(uri.into());
+        self
+    }
+
+    /// Base URI of the IMDB website, used by `title_exists` (default `https://www.imdb.com`)
+    pub fn web_base_uri(mut self, uri: impl Into<String>) -> Self {
+        self.config.web_base_uri = trim_uri(uri.into());
+        self
+    }
+
+    /// User agent sent to the JSON API
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config.user_agent = user_agent.into();
+        self
+    }
+
+    /// App key used to request temporary credentials
+    pub fn app_key(mut self, app_key: impl Into<String>) -> Self {
+        self.config.app_key = app_key.into();
+        self
+    }
+
+    /// Locale sent as `accept-language`, e.g. `de_DE` (default `en_US`)
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.config.locale = locale.into();
+        self
+    }
+
+    /// Policy for retrying connection errors, `429` and `5xx` responses
+    ///
+    /// Use [`RetryPolicy::none`] to disable retries.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.config.retry_policy = policy;
+        self
+    }
+
+    /// Client-side rate limit, shared by every clone of the built client
+    ///
+    /// Use [`RateLimit::unlimited`] to disable it. [`build`](Self::build)
+    /// fails unless `requests_per_second` is positive.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.config.rate_limit = rate_limit;
+        self
+    }
+
+    /// Cache API responses in `cache`
+    ///
+    /// ```no_run
+    /// use imdb_api::{ImdbClient, MemoryCache};
+    ///
+    /// let client = ImdbClient::builder()
+    ///     .cache(MemoryCache::new(10_000))
+    ///     .build()?;
+    /// # Ok::<(), imdb_api::ImdbApiError>(())
+    /// ```
+    pub fn cache(self, cache: impl Cache + 'static) -> Self {
+        self.shared_cache(Arc::new(cache))
+    }
+
+    /// Cache API responses in a cache shared with other clients
+    pub fn shared_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.config.cache = Some(cache);
+        self
+    }
+
+    /// How long responses from each endpoint stay cached
+    pub fn cache_policy(mut self, policy: CachePolicy) -> Self {
+        self.config.cache_policy = policy;
+        self
+    }
+
+    /// Timeout for establishing connections
+    ///
+    /// Ignored when a custom HTTP client or transport is provided.
+    #[cfg(feature = "reqwest")]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for each read from the connection
+    ///
+    /// Ignored when a custom HTTP client or transport is provided.
+    #[cfg(feature = "reqwest")]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Total timeout for each request, from connecting to reading the body
+    ///
+    /// Ignored when a custom HTTP client or transport is provided.
+    #[cfg(feature = "reqwest")]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Use a preconfigured `reqwest::Client`, e.g. with proxies or custom TLS roots
+    ///
+    /// Ignored when a custom transport is provided.
+    #[cfg(feature = "reqwest")]
+    pub fn http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Clock dating request signatures (default the system clock)
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.config.signer = Signer::with_clock(clock);
+        self
+    }
+
+    /// How the client waits between retries and for the rate limiter
+    ///
+    /// The default, [`TokioSleep`], needs a Tokio runtime; provide another
+    /// [`Sleep`] to drive the client from a different runtime.
+    pub fn sleep(mut self, sleep: impl Sleep + 'static) -> Self {
+        self.config.sleep = Arc::new(sleep);
+        self
+    }
+
+    /// Send requests through `transport` instead of `reqwest`
+    pub fn transport(self, transport: impl HttpTransport + 'static) -> Self {
+        self.shared_transport(Arc::new(transport))
+    }
+
+    /// Send requests through a transport shared with other clients
+    pub fn shared_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Build the client
+    ///
+    /// Fails if the rate limit is invalid or the HTTP client cannot be
+    /// initialized, and without a transport if the `reqwest` feature is disabled.
+    pub fn build(self) -> Result<ImdbClient> {
+        self.config.rate_limit.validate()?;
+        if let Some(transport) = self.transport {
+            return Ok(ImdbClient::from_parts(transport, self.config));
+        }
+        self.build_reqwest()
+    }
+
+    #[cfg(not(feature = "reqwest"))]
+    fn build_reqwest(self) -> Result<ImdbClient> {
+        Err(crate::error::ImdbApiError::MissingTransport)
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn build_reqwest(self) -> Result<ImdbClient> {
+        let http_client = match self.http_client {
+            Some(http_client) => http_client,
+            None => {
+                let mut builder = HttpClient::builder();
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.read_timeout {
+                    builder = builder.read_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                builder.build()?
+            }
+        };
+
+        let transport = Arc::new(ReqwestTransport::new(http_client));
+        Ok(ImdbClient::from_parts(transport, self.config))
+    }
+}
+
+impl Default for ImdbClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn trim_uri(uri: String) -> String {
+    uri.trim_end_matches('/').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_overrides_defaults() {
+        let builder = ImdbClientBuilder::new()
+            .base_uri("http://localhost:8080/")
+            .locale("fr_FR")
+            .user_agent("test-agent");
+
+        assert_eq!(builder.config.base_uri, "http://localhost:8080");
+        assert_eq!(builder.config.search_base_uri, SEARCH_BASE_URI);
+        assert_eq!(builder.config.locale, "fr_FR");
+        assert_eq!(builder.config.user_agent, "test-agent");
+        #[cfg(feature = "reqwest")]
+        assert!(builder.build().is_ok());
+        #[cfg(not(feature = "reqwest"))]
+        assert!(matches!(builder.build(), Err(crate::ImdbApiError::MissingTransport)));
+    }
+}
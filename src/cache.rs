@@ -0,0 +1,261 @@
+//! Response caching
+//!
+//! [`ImdbClient`](crate::ImdbClient) consults its cache before sending a
+//! signed API request and stores successful responses for the TTL that the
+//! [`CachePolicy`] assigns to the endpoint.
+
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Storage for API responses
+///
+/// Implementations must be safe to share between tasks. Errors are not
+/// reported: a cache that fails to read or write behaves like a miss.
+pub trait Cache: Send + Sync + fmt::Debug {
+    /// Return the cached value for `key`, unless it is missing or expired
+    fn get(&self, key: &str) -> Option<Value>;
+
+    /// Store `value` for `key` for the given time to live
+    fn insert(&self, key: &str, value: Value, ttl: Duration);
+
+    /// Remove the value cached for `key`
+    fn remove(&self, key: &str);
+}
+
+/// How long responses from each endpoint stay cached
+///
+/// TTLs are looked up by endpoint template (e.g. `/title/{imdb_id}/fullcredits`)
+/// using the longest matching prefix, falling back to `default_ttl`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachePolicy {
+    /// TTL for endpoints without a more specific rule; `None` disables caching
+    pub default_ttl: Option<Duration>,
+    rules: Vec<(String, Option<Duration>)>,
+}
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+impl CachePolicy {
+    /// A policy caching every endpoint for `default_ttl`
+    pub fn new(default_ttl: Duration) -> Self {
+        Self {
+            default_ttl: Some(default_ttl),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Cache endpoints starting with `prefix` for `ttl`
+    pub fn with_ttl(mut self, prefix: impl Into<String>, ttl: Duration) -> Self {
+        self.rules.push((prefix.into(), Some(ttl)));
+        self
+    }
+
+    /// Never cache endpoints starting with `prefix`
+    pub fn without_caching(mut self, prefix: impl Into<String>) -> Self {
+        self.rules.push((prefix.into(), None));
+        self
+    }
+
+    /// The TTL for an endpoint template, or `None` if it must not be cached
+    pub fn ttl_for(&self, endpoint: &str) -> Option<Duration> {
+        self.rules
+            .iter()
+            .filter(|(prefix, _)| endpoint.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default_ttl, |(_, ttl)| *ttl)
+    }
+}
+
+impl Default for CachePolicy {
+    /// Titles and names for a day, charts and news for an hour, and
+    /// rarely changing sections such as credits and trivia for a week
+    fn default() -> Self {
+        Self::new(DAY)
+            .with_ttl("/chart/", HOUR)
+            .with_ttl("/title/{imdb_id}/news", HOUR)
+            .with_ttl("/title/{imdb_id}/fullcredits", 7 * DAY)
+            .with_ttl("/title/{imdb_id}/trivia", 7 * DAY)
+            .with_ttl("/title/{imdb_id}/goofs", 7 * DAY)
+            .with_ttl("/title/{imdb_id}/quotes", 7 * DAY)
+            .with_ttl("/title/{imdb_id}/soundtracks", 7 * DAY)
+            .with_ttl("/title/{imdb_id}/technical", 7 * DAY)
+            .with_ttl("/title/{imdb_id}/companies", 7 * DAY)
+    }
+}
+
+/// In-memory cache evicting the least recently used entries
+#[derive(Debug)]
+pub struct MemoryCache {
+    /// Values and their expiry time; `None` never expires
+    entries: Mutex<LruCache<String, (Value, Option<SystemTime>)>>,
+}
+
+impl MemoryCache {
+    /// Create a cache holding at most `capacity` responses
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some((value, expires_at)) if expires_at.is_none_or(|at| at > SystemTime::now()) => {
+                Some(value.clone())
+            }
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: &str, value: Value, ttl: Duration) {
+        // A TTL too large to represent means the value never expires.
+        let expires_at = SystemTime::now().checked_add(ttl);
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.put(key.to_string(), (value, expires_at));
+    }
+
+    fn remove(&self, key: &str) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.pop(key);
+    }
+}
+
+/// Cache storing one JSON file per response in a directory
+///
+/// Files are named after the SHA-256 of the key, and survive restarts, which
+/// makes this cache handy during local development.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    /// Expiration as seconds since the Unix epoch
+    expires_at: u64,
+    value: Value,
+}
+
+impl DiskCache {
+    /// Create a cache in `dir`, which is created if missing
+    pub fn new(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        let digest = Sha256::digest(key.as_bytes());
+        let name: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        self.dir.join(format!("{}.json", name))
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl Cache for DiskCache {
+    fn get(&self, key: &str) -> Option<Value> {
+        let path = self.path_for(key);
+        let bytes = std::fs::read(&path).ok()?;
+        let entry: DiskEntry = serde_json::from_slice(&bytes).ok()?;
+
+        if entry.expires_at <= unix_now() {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+        Some(entry.value)
+    }
+
+    fn insert(&self, key: &str, value: Value, ttl: Duration) {
+        let entry = DiskEntry {
+            expires_at: unix_now().saturating_add(ttl.as_secs()),
+            value,
+        };
+        let Ok(bytes) = serde_json::to_vec(&entry) else {
+            return;
+        };
+
+        // Write then rename so concurrent readers never see a partial file.
+        let path = self.path_for(key);
+        let tmp = path.with_extension(format!("{}.tmp", fastrand::u64(..)));
+        if std::fs::write(&tmp, bytes).is_ok() && std::fs::rename(&tmp, &path).is_err() {
+            let _ = std::fs::remove_file(&tmp);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let _ = std::fs::remove_file(self.path_for(key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_policy_uses_longest_prefix() {
+        let policy = CachePolicy::default().without_caching("/title/{imdb_id}/userreviews");
+
+        assert_eq!(policy.ttl_for("/chart/tvmeter"), Some(HOUR));
+        assert_eq!(policy.ttl_for("/title/{imdb_id}/fullcredits"), Some(7 * DAY));
+        assert_eq!(policy.ttl_for("/title/{imdb_id}/auxiliary"), Some(DAY));
+        assert_eq!(policy.ttl_for("/title/{imdb_id}/userreviews"), None);
+    }
+
+    #[test]
+    fn test_memory_cache_expires_and_evicts() {
+        let cache = MemoryCache::new(2);
+        cache.insert("a", json!(1), Duration::from_secs(60));
+        cache.insert("b", json!(2), Duration::ZERO);
+        assert_eq!(cache.get("a"), Some(json!(1)));
+        assert_eq!(cache.get("b"), None);
+
+        cache.insert("c", json!(3), Duration::from_secs(60));
+        cache.insert("d", json!(4), Duration::from_secs(60));
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("d"), Some(json!(4)));
+    }
+
+    #[test]
+    fn test_huge_ttl_never_expires() {
+        let cache = MemoryCache::new(2);
+        cache.insert("a", json!(1), Duration::MAX);
+        assert_eq!(cache.get("a"), Some(json!(1)));
+    }
+
+    #[test]
+    fn test_disk_cache_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("imdb-api-cache-{}", fastrand::u64(..)));
+        let cache = DiskCache::new(&dir).unwrap();
+
+        cache.insert("key", json!({ "title": "Se7en" }), Duration::from_secs(60));
+        assert_eq!(cache.get("key"), Some(json!({ "title": "Se7en" })));
+        assert_eq!(DiskCache::new(&dir).unwrap().get("key"), Some(json!({ "title": "Se7en" })));
+
+        cache.remove("key");
+        assert_eq!(cache.get("key"), None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
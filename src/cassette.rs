@@ -0,0 +1,306 @@
+//! Record-and-replay transport for offline tests
+//!
+//! A [`CassetteTransport`] in record mode forwards requests to another
+//! transport and writes every exchange to a JSON cassette file. In replay
+//! mode it answers from the cassette alone, without touching the network,
+//! so tests using it are fast and deterministic.
+//!
+//! ```no_run
+//! use imdb_api::cassette::CassetteTransport;
+//! use imdb_api::ImdbClient;
+//!
+//! # #[cfg(feature = "reqwest")]
+//! # async fn run() -> imdb_api::Result<()> {
+//! // Records when `IMDB_API_RECORD=1`, replays otherwise.
+//! let transport = CassetteTransport::from_env("tests/cassettes/shawshank.json")?;
+//! let client = ImdbClient::builder().transport(transport).build()?;
+//! let title = client.get_title_typed("tt0111161").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Authentication headers and the temporary credentials returned by the API
+//! are redacted before anything is written.
+
+use crate::error::{ImdbApiError, Result};
+#[cfg(feature = "reqwest")]
+use crate::transport::ReqwestTransport;
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, Method};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Request headers never written to a cassette
+const REDACTED_REQUEST_HEADERS: &[&str] = &[
+    "authorization",
+    "cookie",
+    "x-amz-date",
+    "x-amz-security-token",
+    "x-amzn-authorization",
+];
+
+/// Response headers never written to a cassette
+const REDACTED_RESPONSE_HEADERS: &[&str] = &["set-cookie"];
+
+/// Credential fields replaced in recorded responses
+const REDACTED_CREDENTIALS: &[&str] = &["accessKeyId", "secretAccessKey", "sessionToken"];
+
+/// Whether a cassette is being recorded or replayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// The contents of a cassette file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+/// One recorded request and its response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// `GET` or `POST`
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// The body as text; binary bodies are not supported
+    #[serde(default)]
+    pub body: String,
+}
+
+impl Cassette {
+    /// Read a cassette file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// Write the cassette to `path`, creating its directory if missing
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        // Write then rename so an interrupted test never leaves half a cassette.
+        let tmp = path.with_extension(format!("{}.tmp", fastrand::u64(..)));
+        std::fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&tmp, path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
+        })?;
+        Ok(())
+    }
+}
+
+/// Requests are matched on method, URL and body
+type InteractionKey = (String, String, Option<String>);
+
+fn request_key(method: Method, url: &str, body: Option<&[u8]>) -> InteractionKey {
+    let body = body.map(|body| String::from_utf8_lossy(body).into_owned());
+    (method.as_str().to_string(), url.to_string(), body)
+}
+
+/// Transport recording exchanges to, or replaying them from, a cassette file
+pub struct CassetteTransport {
+    path: PathBuf,
+    state: State,
+}
+
+enum State {
+    Record {
+        inner: Arc<dyn HttpTransport>,
+        cassette: Mutex<Cassette>,
+    },
+    Replay {
+        /// Responses left for each request, in recording order
+        responses: Mutex<HashMap<InteractionKey, Vec<RecordedResponse>>>,
+    },
+}
+
+impl CassetteTransport {
+    /// Record exchanges with `inner` to the cassette at `path`
+    ///
+    /// The cassette is overwritten, and saved after every exchange.
+    pub fn record(path: impl Into<PathBuf>, inner: impl HttpTransport + 'static) -> Self {
+        Self {
+            path: path.into(),
+            state: State::Record {
+                inner: Arc::new(inner),
+                cassette: Mutex::new(Cassette::default()),
+            },
+        }
+    }
+
+    /// Replay the cassette at `path`
+    ///
+    /// Identical requests get the recorded responses in order; once those run
+    /// out, the last one is repeated. Requests missing from the cassette fail
+    /// with [`ImdbApiError::TransportError`].
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let mut responses: HashMap<InteractionKey, Vec<RecordedResponse>> = HashMap::new();
+        for interaction in Cassette::load(&path)?.interactions {
+            let request = interaction.request;
+            let key = (request.method, request.url, request.body);
+            responses.entry(key).or_default().push(interaction.response);
+        }
+
+        Ok(Self {
+            path,
+            state: State::Replay {
+                responses: Mutex::new(responses),
+            },
+        })
+    }
+
+    /// Record with `reqwest` if the `IMDB_API_RECORD` environment variable is
+    /// set to `1`, and replay otherwise
+    #[cfg(feature = "reqwest")]
+    pub fn from_env(path: impl Into<PathBuf>) -> Result<Self> {
+        match std::env::var(crate::constants::CASSETTE_RECORD_ENV).as_deref() {
+            Ok("1") => Ok(Self::record(path, ReqwestTransport::default())),
+            _ => Self::replay(path),
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        match self.state {
+            State::Record { .. } => CassetteMode::Record,
+            State::Replay { .. } => CassetteMode::Replay,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn replay_response(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let State::Replay { responses } = &self.state else {
+            unreachable!("replaying in record mode");
+        };
+
+        let key = request_key(request.method, &request.url, request.body.as_deref());
+        let mut responses = responses.lock().unwrap_or_else(|e| e.into_inner());
+        let recorded = match responses.get_mut(&key) {
+            Some(queue) if queue.len() > 1 => queue.remove(0),
+            Some(queue) if !queue.is_empty() => queue[0].clone(),
+            _ => {
+                return Err(ImdbApiError::transport(
+                    format!(
+                        "no recorded response for {} {} in {}",
+                        request.method,
+                        request.url,
+                        self.path.display()
+                    ),
+                    false,
+                ))
+            }
+        };
+
+        Ok(HttpResponse {
+            status: recorded.status,
+            headers: recorded.headers,
+            body: recorded.body.into_bytes(),
+        })
+    }
+
+    async fn record_exchange(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let State::Record { inner, cassette } = &self.state else {
+            unreachable!("recording in replay mode");
+        };
+
+        let response = inner.send(request.clone()).await?;
+        let interaction = Interaction {
+            request: redact_request(&request),
+            response: redact_response(&request, &response),
+        };
+
+        let mut cassette = cassette.lock().unwrap_or_else(|e| e.into_inner());
+        cassette.interactions.push(interaction);
+        cassette.save(&self.path)?;
+        Ok(response)
+    }
+}
+
+impl HttpTransport for CassetteTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            match self.mode() {
+                CassetteMode::Record => self.record_exchange(request).await,
+                CassetteMode::Replay => self.replay_response(&request),
+            }
+        })
+    }
+}
+
+impl fmt::Debug for CassetteTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CassetteTransport")
+            .field("path", &self.path)
+            .field("mode", &self.mode())
+            .finish()
+    }
+}
+
+fn redact_request(request: &HttpRequest) -> RecordedRequest {
+    RecordedRequest {
+        method: request.method.as_str().to_string(),
+        url: request.url.clone(),
+        headers: keep_headers(&request.headers, REDACTED_REQUEST_HEADERS),
+        body: request.body.as_deref().map(|body| String::from_utf8_lossy(body).into_owned()),
+    }
+}
+
+fn redact_response(request: &HttpRequest, response: &HttpResponse) -> RecordedResponse {
+    let mut body = response.text();
+
+    // Replayed credentials never expire, so replays do not depend on the clock.
+    if request.url.contains("/authentication/") {
+        if let Ok(mut json) = serde_json::from_str::<Value>(&body) {
+            if let Some(resource) = json.get_mut("resource").and_then(Value::as_object_mut) {
+                for field in REDACTED_CREDENTIALS {
+                    if resource.contains_key(*field) {
+                        resource.insert(field.to_string(), Value::from("REDACTED"));
+                    }
+                }
+                resource.remove("expirationTimeStamp");
+            }
+            body = json.to_string();
+        }
+    }
+
+    RecordedResponse {
+        status: response.status,
+        headers: keep_headers(&response.headers, REDACTED_RESPONSE_HEADERS),
+        body,
+    }
+}
+
+fn keep_headers(headers: &[(String, String)], redacted: &[&str]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| !redacted.iter().any(|r| name.eq_ignore_ascii_case(r)))
+        .cloned()
+        .collect()
+}
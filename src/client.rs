@@ -1,71 +1,102 @@
+use crate::builder::{ClientConfig, ImdbClientBuilder};
 use crate::constants::*;
-use crate::error::{ImdbApiError, Result};
-use crate::signer::{Credentials, Signer};
+use crate::endpoint::Endpoint;
+use crate::error::{ImdbApiError, OptionalExt, Result};
+use crate::id::{is_known_id, IntoId, NameId, TitleId};
+use crate::models::*;
+use crate::query::normalize_search_query;
+use crate::rate_limit::RateLimiter;
+use crate::retry::{is_retryable_status, retry_after};
+use crate::signer::{Credentials, SigningRequest};
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+use chrono::{DateTime, Utc};
 use regex::Regex;
-use reqwest::Client as HttpClient;
-use serde_json::Value;
-use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::RwLock;
 
 /// IMDB API client
+///
+/// Temporary credentials and the client-side rate limit are shared by every
+/// clone of the client. Credentials are refreshed shortly before they expire.
 #[derive(Debug, Clone)]
 pub struct ImdbClient {
-    http_client: HttpClient,
+    transport: Arc<dyn HttpTransport>,
+    config: Arc<ClientConfig>,
+    credentials: Arc<RwLock<Option<Credentials>>>,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl ImdbClient {
-    /// Create a new IMDB API client
+    /// Create a new IMDB API client with the default settings
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be initialized, like
+    /// `reqwest::Client::new`, or if the `reqwest` feature is disabled. Use
+    /// [`ImdbClient::builder`] to handle the error or provide a transport.
     pub fn new() -> Self {
+        Self::builder()
+            .build()
+            .expect("failed to initialize the HTTP client")
+    }
+
+    /// Create a builder to configure endpoints, locale, timeouts or the HTTP client
+    pub fn builder() -> ImdbClientBuilder {
+        ImdbClientBuilder::new()
+    }
+
+    pub(crate) fn from_parts(transport: Arc<dyn HttpTransport>, config: ClientConfig) -> Self {
         Self {
-            http_client: HttpClient::new(),
+            transport,
+            rate_limiter: Arc::new(RateLimiter::new(config.rate_limit.clone())),
+            config: Arc::new(config),
+            credentials: Arc::new(RwLock::new(None)),
         }
     }
 
     /// Check if a title exists on IMDB
-    pub async fn title_exists(&self, imdb_id: &str) -> Result<bool> {
-        if !validate_imdb_id(imdb_id) {
+    pub async fn title_exists(&self, imdb_id: impl IntoId<TitleId>) -> Result<bool> {
+        let Ok::<TitleId, _>(imdb_id) = imdb_id.into_id() else {
             return Ok(false);
-        }
+        };
 
-        let url = format!("https://www.imdb.com/title/{}/", imdb_id);
+        let url = format!("{}/title/{}/", self.config.web_base_uri, imdb_id);
 
         let response = self
-            .http_client
-            .get(&url)
-            .header("User-Agent", "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
-            .send()
+            .send_with_retry(|| Ok(HttpRequest::get(&url).header("User-Agent", WEB_USER_AGENT)))
             .await?;
 
         // Accept 200 OK or redirect status codes (301, 302, 303, 307, 308)
-        let status = response.status();
-        Ok(status.is_success() || status.is_redirection())
+        Ok(response.is_success() || response.is_redirection())
     }
 
     /// Search for titles or names on IMDB
+    ///
+    /// The query is normalized with [`normalize_search_query`].
     pub async fn search(&self, query: &str) -> Result<Value> {
-        let clean_q = query
-            .replace(' ', "_")
-            .chars()
-            .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_' || *c == '.' || *c == '~')
-            .collect::<String>()
-            .to_lowercase();
-
-        let first_char = clean_q
-            .chars()
-            .next()
-            .map(|c| c.to_string())
-            .unwrap_or_else(|| "a".to_string());
+        let clean_q = normalize_search_query(query)?;
+        let first_char = clean_q.chars().next().unwrap_or('a').to_string();
 
         let search_url = urlencoding::encode(&clean_q);
         let url = format!(
             "{}/suggests/{}/{}.json",
-            SEARCH_BASE_URI, first_char, search_url
+            self.config.search_base_uri,
+            urlencoding::encode(&first_char),
+            search_url
         );
 
-        let response = self.http_client.get(&url).send().await?;
-        let text = response.text().await?;
+        let response = self.send_with_retry(|| Ok(HttpRequest::get(&url))).await?;
+        if !response.is_success() {
+            return Err(error_for_response(&response, query));
+        }
+
+        let text = response.text();
 
         // Parse the special IMDB suggest format (imdb$NAME({...}))
-        let re = Regex::new(r#"imdb\$\w+\((\{.+\})\)"#).unwrap();
+        let re = Regex::new(r#"imdb\$[^(]*\((\{.*\})\)"#).unwrap();
         if let Some(captures) = re.captures(&text) {
             if let Some(json_str) = captures.get(1) {
                 return Ok(serde_json::from_str(json_str.as_str())?);
@@ -76,234 +107,307 @@ impl ImdbClient {
         Ok(serde_json::from_str(&text)?)
     }
 
-    /// Get popular titles
-    pub async fn get_popular_titles(&self) -> Result<Value> {
-        self.get_resource(CHART_TITLEMETER, "").await
-    }
-
-    /// Get popular TV shows
-    pub async fn get_popular_shows(&self) -> Result<Value> {
-        self.get_resource(CHART_TVMETER, "").await
-    }
-
-    /// Get popular movies
-    pub async fn get_popular_movies(&self) -> Result<Value> {
-        self.get_resource(CHART_MOVIMETER, "").await
-    }
-
-    /// Get title information
-    pub async fn get_title(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/auxiliary", imdb_id)
-            .await
-    }
-
-    /// Get name information
-    pub async fn get_name(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/name/{imdb_id}/fulldetails", imdb_id)
-            .await
-    }
-
-    /// Get name filmography
-    pub async fn get_name_filmography(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/name/{imdb_id}/filmography", imdb_id)
-            .await
-    }
-
-    /// Get name images
-    pub async fn get_name_images(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/name/{imdb_id}/images", imdb_id)
-            .await
-    }
-
-    /// Get name videos
-    pub async fn get_name_videos(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/name/{imdb_id}/videos", imdb_id)
-            .await
-    }
-
-    /// Get title genres
-    pub async fn get_title_genres(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/genres", imdb_id)
-            .await
-    }
-
-    /// Get title credits
-    pub async fn get_title_credits(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/fullcredits", imdb_id)
-            .await
-    }
-
-    /// Get title quotes
-    pub async fn get_title_quotes(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/quotes", imdb_id)
-            .await
-    }
-
-    /// Get title ratings
-    pub async fn get_title_ratings(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/ratings", imdb_id)
-            .await
-    }
-
-    /// Get title connections
-    pub async fn get_title_connections(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/connections", imdb_id)
-            .await
-    }
-
-    /// Get title similarities
-    pub async fn get_title_similarities(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/similarities", imdb_id)
-            .await
-    }
-
-    /// Get title videos
-    pub async fn get_title_videos(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/videos", imdb_id)
-            .await
-    }
-
-    /// Get title news
-    pub async fn get_title_news(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/news", imdb_id)
-            .await
-    }
-
-    /// Get title trivia
-    pub async fn get_title_trivia(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/trivia", imdb_id)
-            .await
-    }
-
-    /// Get title soundtracks
-    pub async fn get_title_soundtracks(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/soundtracks", imdb_id)
-            .await
-    }
-
-    /// Get title goofs
-    pub async fn get_title_goofs(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/goofs", imdb_id)
-            .await
-    }
-
-    /// Get title technical information
-    pub async fn get_title_technical(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/technical", imdb_id)
-            .await
-    }
-
-    /// Get title companies
-    pub async fn get_title_companies(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/companies", imdb_id)
-            .await
-    }
-
-    /// Get title episodes (TV shows)
-    pub async fn get_title_episodes(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/episodes", imdb_id)
-            .await
+    /// Search for titles or names on IMDB, decoding the suggestions
+    pub async fn search_results(&self, query: &str) -> Result<Vec<SearchResult>> {
+        let response = self.search(query).await?;
+        SearchResult::from_suggestions(&response)
+    }
+
+    /// Search for titles, keeping only the given kinds (all kinds if empty)
+    pub async fn search_titles(
+        &self,
+        query: &str,
+        kinds: &[TitleKind],
+    ) -> Result<Vec<TitleSuggestion>> {
+        let results = self.search_results(query).await?;
+        Ok(results
+            .into_iter()
+            .filter_map(|result| match result {
+                SearchResult::Title(title) => Some(title),
+                _ => None,
+            })
+            .filter(|title| kinds.is_empty() || kinds.contains(&title.kind))
+            .collect())
+    }
+
+    /// Search for people
+    pub async fn search_names(&self, query: &str) -> Result<Vec<NameSuggestion>> {
+        let results = self.search_results(query).await?;
+        Ok(results
+            .into_iter()
+            .filter_map(|result| match result {
+                SearchResult::Name(name) => Some(name),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Get title information, or `None` if the title does not exist
+    pub async fn get_title_opt(&self, imdb_id: impl IntoId<TitleId>) -> Result<Option<Title>> {
+        self.get_title_typed(imdb_id).await.optional()
+    }
+
+    /// Get name information, or `None` if the person does not exist
+    pub async fn get_name_opt(&self, imdb_id: impl IntoId<NameId>) -> Result<Option<Name>> {
+        self.get_name_typed(imdb_id).await.optional()
+    }
+
+    /// Get one season of a series
+    ///
+    /// Fails with [`ImdbApiError::EpisodeNotFound`] if the series has no such season.
+    pub async fn get_season(&self, series_id: impl IntoId<TitleId>, season: u32) -> Result<Season> {
+        let series_id: TitleId = series_id.into_id()?;
+        let series = self.get_title_episodes_typed(&series_id).await?;
+        series
+            .seasons
+            .into_iter()
+            .find(|s| s.season == season)
+            .ok_or_else(|| episode_not_found(&series_id, season, None))
+    }
+
+    /// Get one episode of a series by season and episode number
+    ///
+    /// Fails with [`ImdbApiError::EpisodeNotFound`] if the series has no such episode.
+    pub async fn get_episode(
+        &self,
+        series_id: impl IntoId<TitleId>,
+        season: u32,
+        episode: u32,
+    ) -> Result<Episode> {
+        let series_id: TitleId = series_id.into_id()?;
+        let season = self.get_season(&series_id, season).await?;
+        let number = season.season;
+        season
+            .episodes
+            .into_iter()
+            .find(|e| e.episode == episode)
+            .ok_or_else(|| episode_not_found(&series_id, number, Some(episode)))
+    }
+
+    /// Get the episode following `episode_id`, or `None` for the last episode
+    pub async fn next_episode(&self, episode_id: impl IntoId<TitleId>) -> Result<Option<Episode>> {
+        let episode_id: TitleId = episode_id.into_id()?;
+        let series = self.series_of(&episode_id).await?;
+        Ok(series.next_episode(&episode_id).cloned())
+    }
+
+    /// Get the episode preceding `episode_id`, or `None` for the first episode
+    pub async fn previous_episode(
+        &self,
+        episode_id: impl IntoId<TitleId>,
+    ) -> Result<Option<Episode>> {
+        let episode_id: TitleId = episode_id.into_id()?;
+        let series = self.series_of(&episode_id).await?;
+        Ok(series.previous_episode(&episode_id).cloned())
+    }
+
+    /// Get the most recent episode of a series that has already aired
+    pub async fn latest_aired_episode(
+        &self,
+        series_id: impl IntoId<TitleId>,
+    ) -> Result<Option<Episode>> {
+        let series = self.get_title_episodes_typed(series_id).await?;
+        let today = Utc::now().date_naive();
+        Ok(series.latest_aired_episode(today).cloned())
+    }
+
+    /// Get the seasons and episodes of the series an episode belongs to
+    async fn series_of(&self, episode_id: &TitleId) -> Result<Series> {
+        let episode = self.get_title_typed(episode_id).await?;
+        let series_id = episode
+            .parent_title
+            .and_then(|parent| parent.title_id())
+            .ok_or_else(|| ImdbApiError::UnexpectedPayload {
+                endpoint: Endpoint::Title.path().to_string(),
+                path: "parentTitle".to_string(),
+                message: format!("{} is not an episode", episode_id),
+            })?;
+        self.get_title_episodes_typed(series_id).await
+    }
+
+    /// Internal method to get a resource from the API and deserialize it
+    ///
+    /// Used by the methods generated from the [`Endpoint`] table.
+    ///
+    /// A resource that does not match `T` is reported as
+    /// [`ImdbApiError::UnexpectedPayload`] with the JSON path of the mismatch.
+    pub(crate) async fn get_model<T: DeserializeOwned>(&self, endpoint: &str, imdb_id: &str) -> Result<T> {
+        let resource = self.get_resource(endpoint, imdb_id).await?;
+        serde_path_to_error::deserialize(resource).map_err(|e| {
+            ImdbApiError::UnexpectedPayload {
+                endpoint: endpoint.to_string(),
+                path: e.path().to_string(),
+                message: e.into_inner().to_string(),
+            }
+        })
     }
 
-    /// Get title plot
-    pub async fn get_title_plot(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/plot", imdb_id)
-            .await
-    }
+    /// Internal method to get a resource from the API
+    ///
+    /// A response without a `resource` is reported as
+    /// [`ImdbApiError::UnexpectedPayload`] at path `resource`, which
+    /// [`OptionalExt::optional`] maps to `None` like a `404`.
+    /// Responses are served from and stored in the configured cache, if any.
+    /// If the API rejects the signature, the cached credentials are
+    /// refreshed and the request is retried once.
+    pub(crate) async fn get_resource(&self, endpoint: &str, imdb_id: &str) -> Result<Value> {
+        let path = endpoint.replace("{imdb_id}", imdb_id);
+        let url = format!("{}{}", self.config.base_uri, path);
+
+        let cache = self
+            .config
+            .cache
+            .as_deref()
+            .zip(self.config.cache_policy.ttl_for(endpoint));
+        let cache_key = format!("{} {}", self.config.locale, url);
+        if let Some(resource) = cache.and_then(|(cache, _)| cache.get(&cache_key)) {
+            return Ok(resource);
+        }
 
-    /// Get title plot synopsis
-    pub async fn get_title_plot_synopsis(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/plotsynopsis", imdb_id)
-            .await
-    }
+        let signing_request = SigningRequest::get(&url)?;
+        let mut retried = false;
+        loop {
+            let creds = self.credentials().await?;
+
+            // Sign each attempt separately, the signature covers the date.
+            let response = self
+                .send_with_retry(|| {
+                    let auth_headers = self.config.signer.sign_request(&signing_request, &creds)?;
+                    Ok(HttpRequest::get(&url)
+                        .header("content-type", "application/json")
+                        .header("accept-language", &self.config.locale)
+                        .header("x-amz-date", auth_headers.x_amz_date)
+                        .header("x-amz-security-token", auth_headers.x_amz_security_token)
+                        .header("x-amzn-authorization", auth_headers.x_amzn_authorization)
+                        .header("user-agent", &self.config.user_agent))
+                })
+                .await?;
+
+            let status = response.status;
+            if (status == 401 || status == 403) && !retried {
+                self.invalidate_credentials(&creds).await;
+                retried = true;
+                continue;
+            }
 
-    /// Get title awards
-    pub async fn get_title_awards(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/awards", imdb_id)
-            .await
-    }
+            let id = if imdb_id.is_empty() { &path } else { imdb_id };
+            if !response.is_success() {
+                return Err(error_for_response(&response, id));
+            }
 
-    /// Get title releases
-    pub async fn get_title_releases(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/releases", imdb_id)
-            .await
+            let json: Value = response.json()?;
+            let resource = match json.get("resource") {
+                Some(resource) if !resource.is_null() => resource.clone(),
+                _ => {
+                    return Err(ImdbApiError::UnexpectedPayload {
+                        endpoint: endpoint.to_string(),
+                        path: "resource".to_string(),
+                        message: format!("missing or null resource for {}", id),
+                    })
+                }
+            };
+            if let Some((cache, ttl)) = cache {
+                cache.insert(&cache_key, resource.clone(), ttl);
+            }
+            return Ok(resource);
+        }
     }
 
-    /// Get title versions
-    pub async fn get_title_versions(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/versions", imdb_id)
-            .await
-    }
+    /// Send a request, retrying transient failures according to the retry policy
+    ///
+    /// Every attempt waits for the rate limiter. `request` is called again for
+    /// every attempt. Once the retries are exhausted the last response is
+    /// returned as is, whatever its status.
+    async fn send_with_retry(
+        &self,
+        mut request: impl FnMut() -> Result<HttpRequest>,
+    ) -> Result<HttpResponse> {
+        let policy = &self.config.retry_policy;
+        let start = Instant::now();
+        let mut retry = 0;
+
+        loop {
+            let request = request()?;
+            let result = {
+                let _permit = self.rate_limiter.acquire(self.config.sleep.as_ref()).await;
+                self.transport.send(request).await
+            };
+
+            let delay = match &result {
+                Ok(response) if is_retryable_status(response.status) => {
+                    retry_after(response).unwrap_or_else(|| policy.backoff(retry))
+                }
+                Err(error) if error.is_retryable() => policy.backoff(retry),
+                _ => return result,
+            };
+
+            if retry >= policy.max_retries || start.elapsed() + delay > policy.max_elapsed {
+                return result;
+            }
 
-    /// Get title user reviews
-    pub async fn get_title_user_reviews(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/userreviews", imdb_id)
-            .await
+            self.config.sleep.sleep(delay).await;
+            retry += 1;
+        }
     }
 
-    /// Get title metacritic reviews
-    pub async fn get_title_metacritic_reviews(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/metacritic", imdb_id)
-            .await
-    }
+    /// Return the cached credentials, fetching new ones if they are missing
+    /// or about to expire
+    async fn credentials(&self) -> Result<Credentials> {
+        let margin = chrono::Duration::seconds(CREDENTIALS_REFRESH_MARGIN_SECS);
 
-    /// Get title images
-    pub async fn get_title_images(&self, imdb_id: &str) -> Result<Value> {
-        self.get_resource("/title/{imdb_id}/images", imdb_id)
-            .await
-    }
+        if let Some(creds) = self.credentials.read().await.as_ref() {
+            if !creds.expires_within(margin) {
+                return Ok(creds.clone());
+            }
+        }
 
-    /// Internal method to get a resource from the API
-    async fn get_resource(&self, endpoint: &str, imdb_id: &str) -> Result<Value> {
-        let path = endpoint.replace("{imdb_id}", imdb_id);
-        let url = format!("{}{}", BASE_URI, path);
+        // Another task may have refreshed the credentials while we waited
+        // for the write lock.
+        let mut cached = self.credentials.write().await;
+        if let Some(creds) = cached.as_ref() {
+            if !creds.expires_within(margin) {
+                return Ok(creds.clone());
+            }
+        }
 
         let creds = self.get_credentials().await?;
-        let auth_headers = Signer::sign(&url, &creds)?;
-
-        let response = self
-            .http_client
-            .get(&url)
-            .header("content-type", "application/json")
-            .header("accept-language", "en_US")
-            .header("x-amz-date", auth_headers.x_amz_date)
-            .header("x-amz-security-token", auth_headers.x_amz_security_token)
-            .header("x-amzn-authorization", auth_headers.x_amzn_authorization)
-            .header("user-agent", USER_AGENT)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(ImdbApiError::ApiError(format!(
-                "API returned status: {}",
-                response.status()
-            )));
+        *cached = Some(creds.clone());
+        Ok(creds)
+    }
+
+    /// Drop the cached credentials if they are still the rejected ones
+    async fn invalidate_credentials(&self, rejected: &Credentials) {
+        let mut cached = self.credentials.write().await;
+        if cached
+            .as_ref()
+            .is_some_and(|creds| creds.session_token == rejected.session_token)
+        {
+            *cached = None;
         }
-
-        let json: Value = response.json().await?;
-        Ok(json["resource"].clone())
     }
 
     /// Get temporary credentials for API access
     async fn get_credentials(&self) -> Result<Credentials> {
-        let url = format!("{}/authentication/credentials/temporary/ios82?=", BASE_URI);
+        let url = format!(
+            "{}/authentication/credentials/temporary/ios82?=",
+            self.config.base_uri
+        );
 
-        let mut body = HashMap::new();
-        body.insert("appKey", APP_KEY);
+        let body = serde_json::to_vec(&json!({ "appKey": self.config.app_key }))?;
 
         let response = self
-            .http_client
-            .post(&url)
-            .header("content-type", "application/json")
-            .json(&body)
-            .send()
+            .send_with_retry(|| {
+                Ok(HttpRequest::post(&url)
+                    .header("content-type", "application/json")
+                    .body(body.clone()))
+            })
             .await?;
 
-        if !response.status().is_success() {
-            return Err(ImdbApiError::AuthenticationError);
+        if !response.is_success() {
+            return Err(error_for_response(&response, "credentials"));
         }
 
-        let json: Value = response.json().await?;
+        let json: Value = response.json()?;
 
         let resource = json["resource"]
             .as_object()
@@ -322,10 +426,28 @@ impl ImdbClient {
                 .as_str()
                 .ok_or(ImdbApiError::AuthenticationError)?
                 .to_string(),
+            expiration: resource
+                .get("expirationTimeStamp")
+                .and_then(Value::as_str)
+                .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+                .map(|ts| ts.with_timezone(&Utc)),
         })
     }
 }
 
+fn episode_not_found(series_id: &TitleId, season: u32, episode: Option<u32>) -> ImdbApiError {
+    ImdbApiError::EpisodeNotFound {
+        series: series_id.to_string(),
+        season,
+        episode,
+    }
+}
+
+/// Turn a non-success response into the matching error
+fn error_for_response(response: &HttpResponse, id: &str) -> ImdbApiError {
+    ImdbApiError::from_status(response.status, id, &response.text(), retry_after(response))
+}
+
 impl Default for ImdbClient {
     fn default() -> Self {
         Self::new()
@@ -333,20 +455,69 @@ impl Default for ImdbClient {
 }
 
 /// Validate an IMDB ID
+///
+/// Accepts bare IDs with a known prefix (`tt`, `nm`, `co`, `ch`, `ls`, `ev`,
+/// `ur`), including the 8-digit IDs of recent titles. Use the types in
+/// [`crate::id`] to also accept URLs and API paths.
 pub fn validate_imdb_id(imdb_id: &str) -> bool {
-    let re = Regex::new(r"^[a-zA-Z]{2}[0-9]{7}$").unwrap();
-    re.is_match(imdb_id) && imdb_id.len() == 9
+    is_known_id(imdb_id)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transport::StubTransport;
 
     #[test]
     fn test_validate_imdb_id() {
         assert!(validate_imdb_id("tt0111161"));
         assert!(validate_imdb_id("nm0000151"));
+        assert!(validate_imdb_id("tt10872600"));
         assert!(!validate_imdb_id("invalid"));
         assert!(!validate_imdb_id("tt123"));
+        assert!(!validate_imdb_id("zz0000001"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_resource_is_unexpected_payload() {
+        let client = ImdbClient::builder()
+            .rate_limit(crate::RateLimit::unlimited())
+            .transport(StubTransport::new(|_| HttpResponse::new(200, r#"{"resource":null}"#)))
+            .build()
+            .unwrap();
+
+        let err = client.get_title("tt0111161").await.unwrap_err();
+        assert!(matches!(
+            &err,
+            ImdbApiError::UnexpectedPayload { endpoint, path, .. }
+                if endpoint == "/title/{imdb_id}/auxiliary" && path == "resource"
+        ));
+        assert!(client.get_title_opt("tt0111161").await.unwrap().is_none());
+        assert!(client.get_title_ratings_typed("tt0111161").await.optional().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_missing_episode_is_episode_not_found() {
+        let series = r#"{"resource":{"id":"/title/tt0903747/","title":"Breaking Bad","seasons":[
+            {"season":1,"episodes":[{"id":"/title/tt0959621/","title":"Pilot","season":1,"episode":1}]}]}}"#;
+        let client = ImdbClient::builder()
+            .rate_limit(crate::RateLimit::unlimited())
+            .transport(StubTransport::new(move |_| HttpResponse::new(200, series)))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.get_episode("tt0903747", 1, 1).await.unwrap().title, "Pilot");
+        let err = client.get_episode("tt0903747", 1, 9).await.unwrap_err();
+        assert!(matches!(
+            &err,
+            ImdbApiError::EpisodeNotFound { series, season: 1, episode: Some(9) } if series == "tt0903747"
+        ));
+        assert_eq!(err.status(), None);
+        assert_eq!(err.to_string(), "Not found: tt0903747 season 1 episode 9");
+        assert!(matches!(
+            client.get_season("tt0903747", 5).await,
+            Err(ImdbApiError::EpisodeNotFound { season: 5, episode: None, .. })
+        ));
+        assert!(client.get_episode("tt0903747", 2, 1).await.optional().unwrap().is_none());
     }
 }
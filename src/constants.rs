@@ -1,42 +1,37 @@
+use crate::endpoint::Endpoint;
+
 /// API constants
 pub const HOST: &str = "api.imdbws.com";
 pub const BASE_URI: &str = "https://api.imdbws.com";
 pub const SEARCH_BASE_URI: &str = "https://v2.sg.media-imdb.com";
+pub const WEB_BASE_URI: &str = "https://www.imdb.com";
 pub const USER_AGENT: &str = "IMDb/8.3.1 (iPhone9,4; iOS 11.2.1)";
 pub const APP_KEY: &str = "76a6cc20-6073-4290-8a2c-951b4580ae4a";
+pub const DEFAULT_LOCALE: &str = "en_US";
+
+/// User agent sent to the IMDB website by `title_exists`
+pub const WEB_USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+/// Number of sections `get_title_full` fetches at once by default
+pub const FULL_TITLE_CONCURRENCY: usize = 4;
+
+/// Number of IDs the batch methods fetch at once by default
+pub const BATCH_CONCURRENCY: usize = 8;
+
+/// Refresh cached temporary credentials this many seconds before they expire
+pub const CREDENTIALS_REFRESH_MARGIN_SECS: i64 = 60;
+
+/// Environment variable switching `CassetteTransport::from_env` to record mode
+pub const CASSETTE_RECORD_ENV: &str = "IMDB_API_RECORD";
 
 /// Simple GET endpoints that require IMDB ID substitution
-pub const SIMPLE_GET_ENDPOINTS: &[(&str, &str)] = &[
-    ("get_name_images", "/name/{imdb_id}/images"),
-    ("get_name_videos", "/name/{imdb_id}/videos"),
-    ("get_title_metacritic_reviews", "/title/{imdb_id}/metacritic"),
-    ("get_title_user_reviews", "/title/{imdb_id}/userreviews"),
-    ("get_title_videos", "/title/{imdb_id}/videos"),
-    ("get_title_images", "/title/{imdb_id}/images"),
-    ("get_title_companies", "/title/{imdb_id}/companies"),
-    ("get_title_technical", "/title/{imdb_id}/technical"),
-    ("get_title_trivia", "/title/{imdb_id}/trivia"),
-    ("get_title_goofs", "/title/{imdb_id}/goofs"),
-    ("get_title_soundtracks", "/title/{imdb_id}/soundtracks"),
-    ("get_title_news", "/title/{imdb_id}/news"),
-    ("get_title_plot", "/title/{imdb_id}/plot"),
-    ("get_title_plot_synopsis", "/title/{imdb_id}/plotsynopsis"),
-    ("get_title_plot_taglines", "/title/{imdb_id}/taglines"),
-    ("get_title_versions", "/title/{imdb_id}/versions"),
-    ("get_title_releases", "/title/{imdb_id}/releases"),
-    ("get_title_quotes", "/title/{imdb_id}/quotes"),
-    ("get_title_connections", "/title/{imdb_id}/connections"),
-    ("get_title_genres", "/title/{imdb_id}/genres"),
-    ("get_title_similarities", "/title/{imdb_id}/similarities"),
-    ("get_title_awards", "/title/{imdb_id}/awards"),
-    ("get_title_ratings", "/title/{imdb_id}/ratings"),
-    ("get_title_credits", "/title/{imdb_id}/fullcredits"),
-    ("get_name", "/name/{imdb_id}/fulldetails"),
-    ("get_name_filmography", "/name/{imdb_id}/filmography"),
-    ("get_title", "/title/{imdb_id}/auxiliary"),
-];
+#[deprecated(note = "use Endpoint")]
+pub const SIMPLE_GET_ENDPOINTS: &[(&str, &str)] = Endpoint::ID_ENDPOINTS;
 
 /// Chart endpoints
-pub const CHART_TITLEMETER: &str = "/chart/titlemeter";
-pub const CHART_TVMETER: &str = "/chart/tvmeter";
-pub const CHART_MOVIMETER: &str = "/chart/moviemeter";
+#[deprecated(note = "use Endpoint::PopularTitles")]
+pub const CHART_TITLEMETER: &str = Endpoint::PopularTitles.path();
+#[deprecated(note = "use Endpoint::PopularShows")]
+pub const CHART_TVMETER: &str = Endpoint::PopularShows.path();
+#[deprecated(note = "use Endpoint::PopularMovies")]
+pub const CHART_MOVIMETER: &str = Endpoint::PopularMovies.path();
@@ -0,0 +1,300 @@
+//! The table of known API endpoints
+//!
+//! Every endpoint is declared once in the table at the bottom of this file,
+//! which generates the [`Endpoint`] enum and the matching `ImdbClient`
+//! methods, both async and blocking. Adding an endpoint is a one-line change.
+
+use crate::client::ImdbClient;
+use crate::error::Result;
+use crate::id::{ImdbId, IntoId, NameId, TitleId};
+use crate::models::*;
+use serde_json::Value;
+
+/// What an endpoint is keyed by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointKind {
+    /// Takes a [`TitleId`]
+    Title,
+    /// Takes a [`NameId`]
+    Name,
+    /// Takes no ID
+    Chart,
+}
+
+macro_rules! endpoints {
+    (
+        title {
+            $($t_variant:ident => $t_path:literal, $t_doc:literal, $t_method:ident $(, $t_typed:ident -> $t_model:ty)?;)*
+        }
+        name {
+            $($n_variant:ident => $n_path:literal, $n_doc:literal, $n_method:ident $(, $n_typed:ident -> $n_model:ty)?;)*
+        }
+        chart {
+            $($c_variant:ident => $c_path:literal, $c_doc:literal, $c_method:ident $(, $c_typed:ident -> $c_model:ty)?;)*
+        }
+    ) => {
+        /// A known API endpoint, usable with [`ImdbClient::get`]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Endpoint {
+            $(#[doc = concat!("`", $t_path, "`")] $t_variant,)*
+            $(#[doc = concat!("`", $n_path, "`")] $n_variant,)*
+            $(#[doc = concat!("`", $c_path, "`")] $c_variant,)*
+        }
+
+        impl Endpoint {
+            /// Every known endpoint
+            pub const ALL: &'static [Endpoint] = &[
+                $(Endpoint::$t_variant,)*
+                $(Endpoint::$n_variant,)*
+                $(Endpoint::$c_variant,)*
+            ];
+
+            /// Method name and path template of every endpoint keyed by an ID,
+            /// backing the deprecated `constants::SIMPLE_GET_ENDPOINTS`
+            pub(crate) const ID_ENDPOINTS: &'static [(&'static str, &'static str)] = &[
+                $((stringify!($t_method), $t_path),)*
+                $((stringify!($n_method), $n_path),)*
+            ];
+
+            /// Path template, with `{imdb_id}` standing for the ID
+            pub const fn path(self) -> &'static str {
+                match self {
+                    $(Endpoint::$t_variant => $t_path,)*
+                    $(Endpoint::$n_variant => $n_path,)*
+                    $(Endpoint::$c_variant => $c_path,)*
+                }
+            }
+
+            /// What the endpoint is keyed by
+            pub fn kind(self) -> EndpointKind {
+                match self {
+                    $(Endpoint::$t_variant => EndpointKind::Title,)*
+                    $(Endpoint::$n_variant => EndpointKind::Name,)*
+                    $(Endpoint::$c_variant => EndpointKind::Chart,)*
+                }
+            }
+
+            /// Name of the `ImdbClient` method returning the raw resource
+            pub fn method_name(self) -> &'static str {
+                match self {
+                    $(Endpoint::$t_variant => stringify!($t_method),)*
+                    $(Endpoint::$n_variant => stringify!($n_method),)*
+                    $(Endpoint::$c_variant => stringify!($c_method),)*
+                }
+            }
+        }
+
+        impl ImdbClient {
+            $(
+                #[doc = $t_doc]
+                pub async fn $t_method(&self, imdb_id: impl IntoId<TitleId>) -> Result<Value> {
+                    let imdb_id: TitleId = imdb_id.into_id()?;
+                    self.get_resource($t_path, imdb_id.as_str()).await
+                }
+
+                $(
+                    #[doc = concat!($t_doc, ", deserialized into [`", stringify!($t_model), "`]")]
+                    pub async fn $t_typed(&self, imdb_id: impl IntoId<TitleId>) -> Result<$t_model> {
+                        let imdb_id: TitleId = imdb_id.into_id()?;
+                        self.get_model($t_path, imdb_id.as_str()).await
+                    }
+                )?
+            )*
+
+            $(
+                #[doc = $n_doc]
+                pub async fn $n_method(&self, imdb_id: impl IntoId<NameId>) -> Result<Value> {
+                    let imdb_id: NameId = imdb_id.into_id()?;
+                    self.get_resource($n_path, imdb_id.as_str()).await
+                }
+
+                $(
+                    #[doc = concat!($n_doc, ", deserialized into [`", stringify!($n_model), "`]")]
+                    pub async fn $n_typed(&self, imdb_id: impl IntoId<NameId>) -> Result<$n_model> {
+                        let imdb_id: NameId = imdb_id.into_id()?;
+                        self.get_model($n_path, imdb_id.as_str()).await
+                    }
+                )?
+            )*
+
+            $(
+                #[doc = $c_doc]
+                pub async fn $c_method(&self) -> Result<Value> {
+                    self.get_resource($c_path, "").await
+                }
+
+                $(
+                    #[doc = concat!($c_doc, ", deserialized into [`", stringify!($c_model), "`]")]
+                    pub async fn $c_typed(&self) -> Result<$c_model> {
+                        self.get_model($c_path, "").await
+                    }
+                )?
+            )*
+        }
+
+        #[cfg(feature = "blocking")]
+        impl crate::blocking::ImdbClient {
+            $(
+                #[doc = $t_doc]
+                pub fn $t_method(&self, imdb_id: impl IntoId<TitleId>) -> Result<Value> {
+                    self.block_on(self.as_async().$t_method(imdb_id))
+                }
+
+                $(
+                    #[doc = concat!($t_doc, ", deserialized into [`", stringify!($t_model), "`]")]
+                    pub fn $t_typed(&self, imdb_id: impl IntoId<TitleId>) -> Result<$t_model> {
+                        self.block_on(self.as_async().$t_typed(imdb_id))
+                    }
+                )?
+            )*
+
+            $(
+                #[doc = $n_doc]
+                pub fn $n_method(&self, imdb_id: impl IntoId<NameId>) -> Result<Value> {
+                    self.block_on(self.as_async().$n_method(imdb_id))
+                }
+
+                $(
+                    #[doc = concat!($n_doc, ", deserialized into [`", stringify!($n_model), "`]")]
+                    pub fn $n_typed(&self, imdb_id: impl IntoId<NameId>) -> Result<$n_model> {
+                        self.block_on(self.as_async().$n_typed(imdb_id))
+                    }
+                )?
+            )*
+
+            $(
+                #[doc = $c_doc]
+                pub fn $c_method(&self) -> Result<Value> {
+                    self.block_on(self.as_async().$c_method())
+                }
+
+                $(
+                    #[doc = concat!($c_doc, ", deserialized into [`", stringify!($c_model), "`]")]
+                    pub fn $c_typed(&self) -> Result<$c_model> {
+                        self.block_on(self.as_async().$c_typed())
+                    }
+                )?
+            )*
+        }
+    };
+}
+
+impl ImdbClient {
+    /// Get the raw resource of any endpoint
+    ///
+    /// `imdb_id` must be a title ID for title endpoints and a name ID for name
+    /// endpoints; it is ignored for charts.
+    ///
+    /// ```no_run
+    /// use imdb_api::{Endpoint, ImdbClient};
+    ///
+    /// # async fn run() -> imdb_api::Result<()> {
+    /// let client = ImdbClient::new();
+    /// let taglines = client.get(Endpoint::TitleTaglines, "tt0111161").await?;
+    /// let popular = client.get(Endpoint::PopularTitles, "").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, endpoint: Endpoint, imdb_id: impl AsRef<str>) -> Result<Value> {
+        let imdb_id = imdb_id.as_ref();
+        let imdb_id = match endpoint.kind() {
+            EndpointKind::Title => imdb_id.parse::<TitleId>()?.to_string(),
+            EndpointKind::Name => imdb_id.parse::<NameId>()?.to_string(),
+            EndpointKind::Chart => String::new(),
+        };
+        self.get_resource(endpoint.path(), &imdb_id).await
+    }
+}
+
+endpoints! {
+    title {
+        Title => "/title/{imdb_id}/auxiliary", "Get title information", get_title, get_title_typed -> Title;
+        TitleGenres => "/title/{imdb_id}/genres", "Get title genres", get_title_genres, get_title_genres_typed -> TitleGenres;
+        TitleCredits => "/title/{imdb_id}/fullcredits", "Get title credits", get_title_credits, get_title_credits_typed -> TitleCredits;
+        TitleQuotes => "/title/{imdb_id}/quotes", "Get title quotes", get_title_quotes, get_title_quotes_typed -> Quotes;
+        TitleRatings => "/title/{imdb_id}/ratings", "Get title ratings", get_title_ratings, get_title_ratings_typed -> TitleRatings;
+        TitleConnections => "/title/{imdb_id}/connections", "Get title connections", get_title_connections, get_title_connections_typed -> Connections;
+        TitleSimilarities => "/title/{imdb_id}/similarities", "Get title similarities", get_title_similarities, get_title_similarities_typed -> Similarities;
+        TitleVideos => "/title/{imdb_id}/videos", "Get title videos", get_title_videos, get_title_videos_typed -> TitleVideos;
+        TitleNews => "/title/{imdb_id}/news", "Get title news", get_title_news, get_title_news_typed -> TitleNews;
+        TitleTrivia => "/title/{imdb_id}/trivia", "Get title trivia", get_title_trivia, get_title_trivia_typed -> Trivia;
+        TitleSoundtracks => "/title/{imdb_id}/soundtracks", "Get title soundtracks", get_title_soundtracks, get_title_soundtracks_typed -> Soundtracks;
+        TitleGoofs => "/title/{imdb_id}/goofs", "Get title goofs", get_title_goofs, get_title_goofs_typed -> Goofs;
+        TitleTechnical => "/title/{imdb_id}/technical", "Get title technical information", get_title_technical, get_title_technical_typed -> TitleTechnical;
+        TitleCompanies => "/title/{imdb_id}/companies", "Get title companies", get_title_companies, get_title_companies_typed -> TitleCompanies;
+        TitleEpisodes => "/title/{imdb_id}/episodes", "Get title episodes (TV shows)", get_title_episodes, get_title_episodes_typed -> Series;
+        TitlePlot => "/title/{imdb_id}/plot", "Get title plot", get_title_plot, get_title_plot_typed -> PlotSummary;
+        TitlePlotSynopsis => "/title/{imdb_id}/plotsynopsis", "Get title plot synopsis", get_title_plot_synopsis, get_title_plot_synopsis_typed -> PlotSynopsis;
+        TitleTaglines => "/title/{imdb_id}/taglines", "Get title taglines", get_title_plot_taglines;
+        TitleAwards => "/title/{imdb_id}/awards", "Get title awards", get_title_awards, get_title_awards_typed -> Awards;
+        TitleReleases => "/title/{imdb_id}/releases", "Get title releases", get_title_releases, get_title_releases_typed -> Releases;
+        TitleVersions => "/title/{imdb_id}/versions", "Get title versions", get_title_versions, get_title_versions_typed -> TitleVersions;
+        TitleAlternateTitles => "/title/{imdb_id}/akas", "Get title alternate titles", get_title_alternate_titles;
+        TitleUserReviews => "/title/{imdb_id}/userreviews", "Get title user reviews", get_title_user_reviews, get_title_user_reviews_typed -> UserReviews;
+        TitleMetacriticReviews => "/title/{imdb_id}/metacritic", "Get title metacritic reviews", get_title_metacritic_reviews, get_title_metacritic_reviews_typed -> MetacriticReviews;
+        TitleImages => "/title/{imdb_id}/images", "Get title images", get_title_images, get_title_images_typed -> TitleImages;
+        TitleParentalGuide => "/title/{imdb_id}/parentalguide", "Get title parental guide", get_title_parental_guide;
+        TitleLocations => "/title/{imdb_id}/locations", "Get title filming locations", get_title_locations;
+        TitleFilmingDates => "/title/{imdb_id}/filmingdates", "Get title filming dates", get_title_filming_dates;
+        TitleKeywords => "/title/{imdb_id}/keywords", "Get title keywords", get_title_keywords;
+        TitleCrazyCredits => "/title/{imdb_id}/crazycredits", "Get title crazy credits", get_title_crazy_credits;
+    }
+    name {
+        Name => "/name/{imdb_id}/fulldetails", "Get name information", get_name, get_name_typed -> Name;
+        NameFilmography => "/name/{imdb_id}/filmography", "Get name filmography", get_name_filmography, get_name_filmography_typed -> Filmography;
+        NameImages => "/name/{imdb_id}/images", "Get name images", get_name_images, get_name_images_typed -> NameImages;
+        NameVideos => "/name/{imdb_id}/videos", "Get name videos", get_name_videos, get_name_videos_typed -> NameVideos;
+    }
+    chart {
+        PopularTitles => "/chart/titlemeter", "Get popular titles", get_popular_titles, get_popular_titles_typed -> Chart;
+        PopularShows => "/chart/tvmeter", "Get popular TV shows", get_popular_shows, get_popular_shows_typed -> Chart;
+        PopularMovies => "/chart/moviemeter", "Get popular movies", get_popular_movies, get_popular_movies_typed -> Chart;
+        TopMovies => "/chart/top", "Get the Top 250 movies", get_top_movies, get_top_movies_typed -> Chart;
+        TopShows => "/chart/toptv", "Get the Top 250 TV shows", get_top_shows, get_top_shows_typed -> Chart;
+        BottomMovies => "/chart/bottom", "Get the bottom 100 movies", get_bottom_movies, get_bottom_movies_typed -> Chart;
+        BoxOffice => "/chart/boxoffice", "Get the box office chart", get_box_office, get_box_office_typed -> Chart;
+        ComingSoon => "/chart/comingsoon", "Get upcoming releases", get_coming_soon, get_coming_soon_typed -> Chart;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_endpoint_table_is_consistent() {
+        let mut paths = HashSet::new();
+        let mut methods = HashSet::new();
+
+        for endpoint in Endpoint::ALL {
+            assert!(paths.insert(endpoint.path()), "duplicate path {}", endpoint.path());
+            assert!(methods.insert(endpoint.method_name()), "duplicate method {}", endpoint.method_name());
+
+            let prefix = match endpoint.kind() {
+                EndpointKind::Title => "/title/{imdb_id}/",
+                EndpointKind::Name => "/name/{imdb_id}/",
+                EndpointKind::Chart => "/chart/",
+            };
+            assert!(endpoint.path().starts_with(prefix), "{:?}", endpoint);
+        }
+
+        assert_eq!(Endpoint::TitleTaglines.method_name(), "get_title_plot_taglines");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_constants_follow_the_table() {
+        use crate::constants::*;
+
+        let ids: Vec<_> = Endpoint::ALL.iter().filter(|e| e.kind() != EndpointKind::Chart).collect();
+        assert_eq!(SIMPLE_GET_ENDPOINTS.len(), ids.len());
+        for endpoint in ids {
+            assert!(SIMPLE_GET_ENDPOINTS.contains(&(endpoint.method_name(), endpoint.path())));
+        }
+        assert!(SIMPLE_GET_ENDPOINTS.contains(&("get_title", "/title/{imdb_id}/auxiliary")));
+        assert_eq!(CHART_TITLEMETER, "/chart/titlemeter");
+        assert_eq!(CHART_TVMETER, "/chart/tvmeter");
+        assert_eq!(CHART_MOVIMETER, "/chart/moviemeter");
+    }
+}
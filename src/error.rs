@@ -1,28 +1,248 @@
+use std::time::Duration;
 use thiserror::Error;
 
+/// Maximum number of characters of a response body kept in an error
+const BODY_SNIPPET_LEN: usize = 512;
+
 /// Error types for the IMDB API client
 #[derive(Error, Debug)]
 pub enum ImdbApiError {
+    #[cfg(feature = "reqwest")]
     #[error("HTTP request error: {0}")]
     RequestError(#[from] reqwest::Error),
 
+    #[error("No HTTP transport: enable the `reqwest` feature or provide a transport")]
+    MissingTransport,
+
+    #[error("HTTP transport error: {source}")]
+    TransportError {
+        source: Box<dyn std::error::Error + Send + Sync>,
+        /// Whether the request may succeed if sent again, e.g. after a timeout
+        retryable: bool,
+    },
+
     #[error("JSON parsing error: {0}")]
     JsonError(#[from] serde_json::Error),
 
     #[error("Invalid IMDB ID format: {0}")]
     InvalidImdbId(String),
 
-    #[error("API error: {0}")]
-    ApiError(String),
+    #[error("Search query has nothing to search for: {0:?}")]
+    EmptySearchQuery(String),
+
+    #[error("Invalid rate limit: {0}")]
+    InvalidRateLimit(String),
+
+    #[error("Invalid URL {url:?}: {reason}")]
+    InvalidUrl { url: String, reason: String },
+
+    #[error("Not found: {id} (status {status})")]
+    NotFound { id: String, status: u16, body: String },
+
+    /// The series exists but has no such season, or no such episode in it
+    #[error(
+        "Not found: {series} season {season}{}",
+        .episode.map(|e| format!(" episode {}", e)).unwrap_or_default()
+    )]
+    EpisodeNotFound {
+        series: String,
+        season: u32,
+        episode: Option<u32>,
+    },
+
+    #[error("Rate limited (status {status}), retry after {retry_after:?}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        status: u16,
+        body: String,
+    },
+
+    #[error("Unauthorized or expired credentials (status {status})")]
+    Unauthorized { status: u16, body: String },
 
-    #[error("Title not found: {0}")]
-    TitleNotFound(String),
+    #[error("Server error (status {status})")]
+    ServerError { status: u16, body: String },
+
+    #[error("Unexpected status {status}")]
+    UnexpectedStatus { status: u16, body: String },
+
+    #[error("Unexpected payload from {endpoint} at {path}: {message}")]
+    UnexpectedPayload {
+        endpoint: String,
+        /// JSON path of the offending value, e.g. `cast[3].name`
+        path: String,
+        message: String,
+    },
 
     #[error("Authentication error")]
     AuthenticationError,
 
+    #[error("Invalid request signature: {0}")]
+    InvalidSignature(String),
+
     #[error("Base64 encoding error: {0}")]
     Base64Error(#[from] base64::DecodeError),
+
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("{0:?} is not a chart endpoint")]
+    NotAChart(crate::endpoint::Endpoint),
+}
+
+impl ImdbApiError {
+    /// Wrap an error of a custom [`HttpTransport`](crate::transport::HttpTransport)
+    pub fn transport(error: impl Into<Box<dyn std::error::Error + Send + Sync>>, retryable: bool) -> Self {
+        Self::TransportError {
+            source: error.into(),
+            retryable,
+        }
+    }
+
+    /// Build the error for a non-success HTTP response
+    ///
+    /// `id` is the IMDB ID or path that was requested; `body` is truncated.
+    pub(crate) fn from_status(
+        status: u16,
+        id: &str,
+        body: &str,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        let body = snippet(body);
+        match status {
+            401 | 403 => Self::Unauthorized { status, body },
+            404 | 410 => Self::NotFound {
+                id: id.to_string(),
+                status,
+                body,
+            },
+            429 => Self::RateLimited {
+                retry_after,
+                status,
+                body,
+            },
+            500..=599 => Self::ServerError { status, body },
+            _ => Self::UnexpectedStatus { status, body },
+        }
+    }
+
+    /// Whether the same request may succeed if sent again later
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            #[cfg(feature = "reqwest")]
+            Self::RequestError(e) => e.is_connect() || e.is_timeout(),
+            Self::TransportError { retryable, .. } => *retryable,
+            Self::RateLimited { .. } | Self::ServerError { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// The HTTP status of the response that caused the error, if any
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::NotFound { status, .. }
+            | Self::RateLimited { status, .. }
+            | Self::Unauthorized { status, .. }
+            | Self::ServerError { status, .. }
+            | Self::UnexpectedStatus { status, .. } => Some(*status),
+            #[cfg(feature = "reqwest")]
+            Self::RequestError(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+}
+
+fn snippet(body: &str) -> String {
+    match body.char_indices().nth(BODY_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}…", &body[..end]),
+        None => body.to_string(),
+    }
 }
 
 pub type Result<T> = std::result::Result<T, ImdbApiError>;
+
+/// Turn "not found" errors into `Ok(None)`
+///
+/// ```no_run
+/// use imdb_api::{ImdbClient, OptionalExt};
+///
+/// # async fn run() -> imdb_api::Result<()> {
+/// let client = ImdbClient::new();
+/// match client.get_title_credits_typed("tt0111161").await.optional()? {
+///     Some(credits) => println!("{} cast members", credits.cast.len()),
+///     None => println!("no such title"),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait OptionalExt<T> {
+    /// Map [`ImdbApiError::NotFound`], [`ImdbApiError::EpisodeNotFound`] and
+    /// a successful response without a `resource` to `Ok(None)`, keeping
+    /// every other error
+    fn optional(self) -> Result<Option<T>>;
+}
+
+impl<T> OptionalExt<T> for Result<T> {
+    fn optional(self) -> Result<Option<T>> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(ImdbApiError::NotFound { .. } | ImdbApiError::EpisodeNotFound { .. }) => Ok(None),
+            Err(ImdbApiError::UnexpectedPayload { path, .. }) if path == "resource" => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_status() {
+        let err = ImdbApiError::from_status(404, "tt0000000", "missing", None);
+        assert!(matches!(&err, ImdbApiError::NotFound { id, .. } if id == "tt0000000"));
+        assert_eq!(err.status(), Some(404));
+        assert!(!err.is_retryable());
+
+        let err = ImdbApiError::from_status(429, "", "", Some(Duration::from_secs(3)));
+        assert!(matches!(err, ImdbApiError::RateLimited { retry_after: Some(_), .. }));
+        assert!(err.is_retryable());
+
+        assert!(ImdbApiError::from_status(503, "", "", None).is_retryable());
+        assert!(matches!(
+            ImdbApiError::from_status(403, "", "", None),
+            ImdbApiError::Unauthorized { .. }
+        ));
+    }
+
+    #[test]
+    fn test_optional() {
+        let not_found: Result<()> = Err(ImdbApiError::from_status(404, "tt0000000", "", None));
+        assert!(not_found.optional().unwrap().is_none());
+
+        let invalid: Result<()> = Err(ImdbApiError::InvalidImdbId("zz".to_string()));
+        assert!(invalid.optional().is_err());
+
+        let payload = |path: &str| -> Result<()> {
+            Err(ImdbApiError::UnexpectedPayload {
+                endpoint: "/title/{imdb_id}/auxiliary".to_string(),
+                path: path.to_string(),
+                message: String::new(),
+            })
+        };
+        assert!(payload("resource").optional().unwrap().is_none());
+        assert!(payload("cast[0].name").optional().is_err());
+
+        assert_eq!(Ok(1).optional().unwrap(), Some(1));
+    }
+
+    #[test]
+    fn test_body_snippet_is_truncated() {
+        let body = "é".repeat(1000);
+        let ImdbApiError::ServerError { body, .. } = ImdbApiError::from_status(500, "", &body, None)
+        else {
+            panic!("expected a server error");
+        };
+        assert_eq!(body.chars().count(), BODY_SNIPPET_LEN + 1);
+    }
+}
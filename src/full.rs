@@ -0,0 +1,213 @@
+//! Fetch several sections of a title page at once
+
+use crate::client::ImdbClient;
+use crate::constants::FULL_TITLE_CONCURRENCY;
+use crate::error::Result;
+use crate::id::{IntoId, TitleId};
+use crate::models::*;
+use futures::stream::{self, StreamExt};
+
+macro_rules! title_parts {
+    ($($part:ident => $field:ident: $model:ty = $method:ident,)*) => {
+        /// A section of a title page, fetched from its own endpoint
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum TitlePart {
+            $($part,)*
+        }
+
+        impl TitlePart {
+            /// Every section
+            pub const ALL: &'static [TitlePart] = &[$(TitlePart::$part,)*];
+        }
+
+        /// Sections of a title fetched by [`ImdbClient::get_title_full`]
+        ///
+        /// Each section is `None` if it was not requested, and carries its own
+        /// result otherwise, so one failing section does not hide the others.
+        #[derive(Debug)]
+        pub struct FullTitle {
+            pub id: TitleId,
+            $(pub $field: Option<Result<$model>>,)*
+        }
+
+        // Short-lived, moved straight into `FullTitle`
+        #[allow(clippy::large_enum_variant)]
+        enum PartResult {
+            $($part(Result<$model>),)*
+        }
+
+        impl FullTitle {
+            fn new(id: TitleId) -> Self {
+                Self {
+                    id,
+                    $($field: None,)*
+                }
+            }
+
+            fn set(&mut self, result: PartResult) {
+                match result {
+                    $(PartResult::$part(result) => self.$field = Some(result),)*
+                }
+            }
+        }
+
+        impl ImdbClient {
+            async fn get_title_part(&self, id: &TitleId, part: TitlePart) -> PartResult {
+                match part {
+                    $(TitlePart::$part => PartResult::$part(self.$method(id).await),)*
+                }
+            }
+        }
+    };
+}
+
+title_parts! {
+    Title => title: Title = get_title_typed,
+    Ratings => ratings: TitleRatings = get_title_ratings_typed,
+    Credits => credits: TitleCredits = get_title_credits_typed,
+    Genres => genres: TitleGenres = get_title_genres_typed,
+    Plot => plot: PlotSummary = get_title_plot_typed,
+    PlotSynopsis => plot_synopsis: PlotSynopsis = get_title_plot_synopsis_typed,
+    Awards => awards: Awards = get_title_awards_typed,
+    Releases => releases: Releases = get_title_releases_typed,
+    Versions => versions: TitleVersions = get_title_versions_typed,
+    Trivia => trivia: Trivia = get_title_trivia_typed,
+    Goofs => goofs: Goofs = get_title_goofs_typed,
+    Quotes => quotes: Quotes = get_title_quotes_typed,
+    Soundtracks => soundtracks: Soundtracks = get_title_soundtracks_typed,
+    Technical => technical: TitleTechnical = get_title_technical_typed,
+    Companies => companies: TitleCompanies = get_title_companies_typed,
+    Connections => connections: Connections = get_title_connections_typed,
+    Similarities => similarities: Similarities = get_title_similarities_typed,
+    UserReviews => user_reviews: UserReviews = get_title_user_reviews_typed,
+    MetacriticReviews => metacritic_reviews: MetacriticReviews = get_title_metacritic_reviews_typed,
+    Images => images: TitleImages = get_title_images_typed,
+    Videos => videos: TitleVideos = get_title_videos_typed,
+    News => news: TitleNews = get_title_news_typed,
+    Episodes => episodes: Series = get_title_episodes_typed,
+}
+
+impl ImdbClient {
+    /// Fetch the requested sections of a title concurrently
+    ///
+    /// At most [`FULL_TITLE_CONCURRENCY`] sections are fetched at once. Only an
+    /// invalid ID fails the whole call; every other error is reported in the
+    /// section it affects.
+    ///
+    /// ```no_run
+    /// use imdb_api::{ImdbClient, TitlePart};
+    ///
+    /// # async fn run() -> imdb_api::Result<()> {
+    /// let client = ImdbClient::new();
+    /// let full = client
+    ///     .get_title_full("tt0111161", [TitlePart::Title, TitlePart::Ratings, TitlePart::Credits])
+    ///     .await?;
+    /// if let Some(Ok(ratings)) = &full.ratings {
+    ///     println!("{:?}", ratings.rating);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_title_full(
+        &self,
+        imdb_id: impl IntoId<TitleId>,
+        parts: impl IntoIterator<Item = TitlePart>,
+    ) -> Result<FullTitle> {
+        self.get_title_full_with_concurrency(imdb_id, parts, FULL_TITLE_CONCURRENCY)
+            .await
+    }
+
+    /// Like [`get_title_full`](Self::get_title_full), with at most
+    /// `concurrency` sections fetched at once
+    ///
+    /// A `concurrency` of 0 is treated as 1.
+    pub async fn get_title_full_with_concurrency(
+        &self,
+        imdb_id: impl IntoId<TitleId>,
+        parts: impl IntoIterator<Item = TitlePart>,
+        concurrency: usize,
+    ) -> Result<FullTitle> {
+        let id: TitleId = imdb_id.into_id()?;
+
+        let mut parts: Vec<TitlePart> = parts.into_iter().collect();
+        parts.sort();
+        parts.dedup();
+
+        let mut results = stream::iter(parts)
+            .map(|part| self.get_title_part(&id, part))
+            .buffer_unordered(concurrency.max(1));
+
+        let mut full = FullTitle::new(id.clone());
+        while let Some(result) = results.next().await {
+            full.set(result);
+        }
+        Ok(full)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ImdbApiError;
+    use crate::rate_limit::RateLimit;
+    use crate::retry::RetryPolicy;
+    use crate::transport::{HttpResponse, StubTransport};
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Serves every section slowly, failing the ratings, and records in
+    /// `max_in_flight` how many section requests were in flight at once
+    fn client(max_in_flight: Arc<AtomicUsize>) -> ImdbClient {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let transport = StubTransport::new_async(move |request| {
+            let (in_flight, max_in_flight) = (in_flight.clone(), max_in_flight.clone());
+            Box::pin(async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                Ok(match request.url.ends_with("/ratings") {
+                    true => HttpResponse::new(500, "unavailable"),
+                    false => HttpResponse::new(200, json!({ "resource": { "title": "Se7en" } }).to_string()),
+                })
+            })
+        });
+
+        ImdbClient::builder()
+            .base_uri("http://imdb.test")
+            .rate_limit(RateLimit::unlimited())
+            .retry_policy(RetryPolicy::none())
+            .transport(transport)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_failing_section_keeps_its_error() {
+        let client = client(Arc::default());
+        let parts = [TitlePart::Title, TitlePart::Ratings, TitlePart::Genres, TitlePart::Title];
+
+        let full = client.get_title_full("tt0114369", parts).await.unwrap();
+        assert_eq!(full.title.unwrap().unwrap().title, "Se7en");
+        assert!(full.genres.unwrap().is_ok());
+        assert!(matches!(full.ratings, Some(Err(ImdbApiError::ServerError { status: 500, .. }))));
+        assert!(full.credits.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_is_bounded() {
+        for (concurrency, expected) in [(3, 3), (1, 1), (0, 1)] {
+            let max_in_flight = Arc::new(AtomicUsize::new(0));
+            let full = client(max_in_flight.clone())
+                .get_title_full_with_concurrency("tt0114369", TitlePart::ALL.iter().copied(), concurrency)
+                .await
+                .unwrap();
+
+            assert!(full.news.is_some());
+            assert_eq!(max_in_flight.load(Ordering::SeqCst), expected, "concurrency {}", concurrency);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Typed IMDB identifiers
+//!
+//! Each kind of IMDB ID has its own newtype, so a name ID cannot be passed
+//! where a title ID is expected. IDs parse from the bare form (`tt0111161`),
+//! from API paths (`/title/tt0111161/`) and from full IMDB URLs
+//! (`https://www.imdb.com/title/tt0111161/?ref_=fn_al_tt_1`).
+
+use crate::error::{ImdbApiError, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Common behaviour of the typed IMDB IDs
+pub trait ImdbId: FromStr<Err = ImdbApiError> + fmt::Display + Clone {
+    /// Two-letter prefix of this kind of ID, e.g. `"tt"`
+    const PREFIX: &'static str;
+    /// Minimum number of digits after the prefix
+    const MIN_DIGITS: usize = 7;
+    /// Maximum number of digits after the prefix
+    const MAX_DIGITS: usize = 8;
+
+    /// The ID in its bare form, e.g. `tt0111161`
+    fn as_str(&self) -> &str;
+}
+
+/// Conversion into a typed IMDB ID, accepted by the client methods
+///
+/// Implemented for the ID types themselves and for strings, which are parsed
+/// (and rejected with [`ImdbApiError::InvalidImdbId`]) when the request is made.
+pub trait IntoId<T: ImdbId> {
+    fn into_id(self) -> Result<T>;
+}
+
+impl<T: ImdbId> IntoId<T> for &str {
+    fn into_id(self) -> Result<T> {
+        self.parse()
+    }
+}
+
+impl<T: ImdbId> IntoId<T> for String {
+    fn into_id(self) -> Result<T> {
+        self.parse()
+    }
+}
+
+impl<T: ImdbId> IntoId<T> for &String {
+    fn into_id(self) -> Result<T> {
+        self.parse()
+    }
+}
+
+macro_rules! imdb_id {
+    ($(#[$meta:meta])* $name:ident, $prefix:literal $(, $max_digits:literal)?) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(String);
+
+        impl ImdbId for $name {
+            const PREFIX: &'static str = $prefix;
+            $(const MAX_DIGITS: usize = $max_digits;)?
+
+            fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ImdbApiError;
+
+            fn from_str(s: &str) -> Result<Self> {
+                extract::<Self>(s)
+                    .map(Self)
+                    .ok_or_else(|| ImdbApiError::InvalidImdbId(s.to_string()))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl IntoId<$name> for $name {
+            fn into_id(self) -> Result<$name> {
+                Ok(self)
+            }
+        }
+
+        impl IntoId<$name> for &$name {
+            fn into_id(self) -> Result<$name> {
+                Ok(self.clone())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+imdb_id!(
+    /// A title ID (movie, series, episode, ...), e.g. `tt0111161`
+    TitleId, "tt"
+);
+imdb_id!(
+    /// A name (person) ID, e.g. `nm0000151`
+    NameId, "nm"
+);
+imdb_id!(
+    /// A company ID, e.g. `co0002663`
+    CompanyId, "co"
+);
+imdb_id!(
+    /// A character ID, e.g. `ch0000001`
+    CharacterId, "ch"
+);
+imdb_id!(
+    /// A user list ID, e.g. `ls055592025`
+    ListId, "ls", 10
+);
+imdb_id!(
+    /// An event (awards ceremony, festival) ID, e.g. `ev0000003`
+    EventId, "ev"
+);
+imdb_id!(
+    /// A user ID, e.g. `ur0000001`
+    UserId, "ur", 10
+);
+
+/// Whether `s` is exactly one ID of kind `T`, in its bare form
+fn is_bare<T: ImdbId>(s: &str) -> bool {
+    let Some(digits) = s
+        .get(..2)
+        .filter(|prefix| prefix.eq_ignore_ascii_case(T::PREFIX))
+        .map(|_| &s[2..])
+    else {
+        return false;
+    };
+
+    (T::MIN_DIGITS..=T::MAX_DIGITS).contains(&digits.len())
+        && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Find an ID of kind `T` in a bare ID, an API path or an IMDB URL
+fn extract<T: ImdbId>(s: &str) -> Option<String> {
+    s.trim()
+        .split(['/', '?', '#'])
+        .find(|segment| is_bare::<T>(segment))
+        .map(|segment| segment.to_ascii_lowercase())
+}
+
+/// Whether `s` is a bare ID of any known kind
+pub(crate) fn is_known_id(s: &str) -> bool {
+    is_bare::<TitleId>(s)
+        || is_bare::<NameId>(s)
+        || is_bare::<CompanyId>(s)
+        || is_bare::<CharacterId>(s)
+        || is_bare::<ListId>(s)
+        || is_bare::<EventId>(s)
+        || is_bare::<UserId>(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bare_and_modern_ids() {
+        assert_eq!("tt0111161".parse::<TitleId>().unwrap().as_str(), "tt0111161");
+        assert_eq!("tt10872600".parse::<TitleId>().unwrap().as_str(), "tt10872600");
+        assert_eq!("NM0000151".parse::<NameId>().unwrap().as_str(), "nm0000151");
+        assert_eq!("ls055592025".parse::<ListId>().unwrap().as_str(), "ls055592025");
+        assert!("nm0000151".parse::<TitleId>().is_err());
+        assert!("tt123".parse::<TitleId>().is_err());
+        assert!("tt123456789".parse::<TitleId>().is_err());
+    }
+
+    #[test]
+    fn test_parse_paths_and_urls() {
+        let expected: TitleId = "tt0133093".parse().unwrap();
+        for input in [
+            "/title/tt0133093/",
+            "https://www.imdb.com/title/tt0133093/",
+            "https://m.imdb.com/title/tt0133093/?ref_=fn_al_tt_1",
+            "imdb.com/title/tt0133093#plot",
+        ] {
+            assert_eq!(input.parse::<TitleId>().unwrap(), expected, "{}", input);
+        }
+
+        let name: NameId = "https://www.imdb.com/name/nm0000206/bio".parse().unwrap();
+        assert_eq!(name.to_string(), "nm0000206");
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let id: TitleId = serde_json::from_str("\"/title/tt0111161/\"").unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"tt0111161\"");
+        assert!(serde_json::from_str::<NameId>("\"tt0111161\"").is_err());
+    }
+}
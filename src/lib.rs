@@ -24,14 +24,52 @@
 //! }
 //! ```
 
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
+pub mod cache;
+pub mod cassette;
 pub mod client;
 pub mod constants;
+pub mod endpoint;
 pub mod error;
+pub mod full;
+pub mod id;
+#[cfg(feature = "test-util")]
+pub mod mock;
+pub mod models;
+pub mod query;
+pub mod rate_limit;
+pub mod release;
+pub mod resolve;
+pub mod retry;
 pub mod signer;
+pub mod tracker;
+pub mod transport;
 
+pub use batch::{BatchOptions, BatchProgress};
+pub use builder::ImdbClientBuilder;
+pub use cache::{Cache, CachePolicy, DiskCache, MemoryCache};
+pub use cassette::CassetteTransport;
 pub use client::{validate_imdb_id, ImdbClient};
-pub use error::{ImdbApiError, Result};
-pub use signer::{AuthHeaders, Credentials};
+pub use endpoint::{Endpoint, EndpointKind};
+pub use error::{ImdbApiError, OptionalExt, Result};
+pub use full::{FullTitle, TitlePart};
+pub use id::{
+    CharacterId, CompanyId, EventId, ImdbId, IntoId, ListId, NameId, TitleId, UserId,
+};
+pub use query::normalize_search_query;
+pub use rate_limit::RateLimit;
+pub use resolve::TitleMatch;
+pub use retry::RetryPolicy;
+pub use signer::{AuthHeaders, Clock, Credentials, FixedClock, Signer, SigningRequest, SystemClock};
+pub use tracker::{
+    ChartDiff, ChartMove, ChartSnapshot, ChartStore, ChartTracker, JsonChartStore, MemoryChartStore,
+};
+#[cfg(feature = "reqwest")]
+pub use transport::ReqwestTransport;
+pub use transport::{HttpRequest, HttpResponse, HttpTransport, Sleep, TokioSleep};
 
 /// Validate an IMDB ID (re-exported from client module)
 pub fn is_valid_imdb_id(imdb_id: &str) -> bool {
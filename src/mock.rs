@@ -0,0 +1,365 @@
+//! In-process mock of the IMDB services, enabled by the `test-util` feature
+//!
+//! [`MockServer`] listens on a local port and serves the JSON API, the
+//! suggest service and the website from one address:
+//!
+//! - `POST /authentication/credentials/temporary/ios82` issues credentials
+//! - signed `GET /title/*`, `/name/*` and `/chart/*` serve the resources
+//!   added with [`MockServer::add_resource`], and answer `403` when the AWS3
+//!   signature does not match the issued credentials
+//! - `GET /suggests/{letter}/{query}.json` answers in the JSONP format of
+//!   `v2.sg.media-imdb.com`
+//! - `GET /title/{id}/` without a signature stands for the website
+//!
+//! Faults can be queued per path to exercise retries and error handling, and
+//! credentials can be revoked to exercise their refresh.
+//!
+//! ```no_run
+//! use imdb_api::mock::MockServer;
+//! use serde_json::json;
+//!
+//! # async fn run() -> imdb_api::Result<()> {
+//! let server = MockServer::start().await?;
+//! server.add_resource("/title/tt0111161/auxiliary", json!({ "title": "The Shawshank Redemption" }));
+//! server.fail_next("/title/tt0111161/auxiliary", 503, None);
+//!
+//! let client = server.client_builder().build()?;
+//! let title = client.get_title_typed("tt0111161").await?;
+//! assert_eq!(title.title, "The Shawshank Redemption");
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::transport::StubTransport;
+
+use crate::builder::ImdbClientBuilder;
+use crate::error::Result;
+use crate::signer::{AuthHeaders, Credentials, Signer, SigningRequest};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// A request received by the [`MockServer`], and the status it got
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    pub method: String,
+    /// Path and query, e.g. `/title/tt0111161/auxiliary`
+    pub path: String,
+    pub status: u16,
+}
+
+/// Local HTTP server mimicking the IMDB services
+///
+/// The server stops when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    task: JoinHandle<()>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    resources: HashMap<String, Value>,
+    suggestions: HashMap<String, Value>,
+    web_titles: HashSet<String>,
+    faults: HashMap<String, VecDeque<Fault>>,
+    /// Credentials of each session token still accepted
+    sessions: HashMap<String, Credentials>,
+    credentials_issued: usize,
+    requests: Vec<MockRequest>,
+}
+
+#[derive(Debug, Clone)]
+struct Fault {
+    status: u16,
+    retry_after: Option<u64>,
+}
+
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+}
+
+impl Reply {
+    fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            headers: Vec::new(),
+            body: body.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, json!({ "error": message }))
+    }
+}
+
+impl MockServer {
+    /// Start a server on a free local port, on the current Tokio runtime
+    pub async fn start() -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(MockState::default()));
+
+        let task = tokio::spawn({
+            let state = state.clone();
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(serve(stream, state.clone()));
+                }
+            }
+        });
+
+        Ok(Self { addr, state, task })
+    }
+
+    /// Base URI of the server, e.g. `http://127.0.0.1:41234`
+    pub fn uri(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// A client builder with every base URI pointing at this server
+    pub fn client_builder(&self) -> ImdbClientBuilder {
+        ImdbClientBuilder::new()
+            .base_uri(self.uri())
+            .search_base_uri(self.uri())
+            .web_base_uri(self.uri())
+    }
+
+    /// Serve `resource` at `path`, e.g. `/title/tt0111161/ratings` or `/chart/top`
+    pub fn add_resource(&self, path: &str, resource: Value) {
+        self.state().resources.insert(path.to_string(), resource);
+    }
+
+    /// Serve suggestions (the `d` array) for a normalized query, e.g. `the_matrix`
+    pub fn add_suggestions(&self, query: &str, suggestions: Value) {
+        self.state().suggestions.insert(query.to_string(), suggestions);
+    }
+
+    /// Make the website page of a title exist, for `title_exists`
+    pub fn add_web_title(&self, imdb_id: &str) {
+        self.state().web_titles.insert(imdb_id.to_string());
+    }
+
+    /// Answer the next request for `path` with `status` instead
+    ///
+    /// Faults queue up, so calling this twice fails the next two requests.
+    pub fn fail_next(&self, path: &str, status: u16, retry_after: Option<u64>) {
+        self.state()
+            .faults
+            .entry(path.to_string())
+            .or_default()
+            .push_back(Fault { status, retry_after });
+    }
+
+    /// Stop accepting every credentials issued so far, as if they had expired
+    pub fn revoke_credentials(&self) {
+        self.state().sessions.clear();
+    }
+
+    /// Number of credentials issued so far
+    pub fn credentials_issued(&self) -> usize {
+        self.state().credentials_issued
+    }
+
+    /// Every request received so far, in order
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state().requests.clone()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Serve one request, then close the connection
+async fn serve(stream: TcpStream, state: Arc<Mutex<MockState>>) {
+    let mut stream = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    if stream.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+        return;
+    }
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return;
+    };
+    let (method, target) = (method.to_string(), target.to_string());
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if stream.read_line(&mut line).await.unwrap_or(0) == 0 {
+            return;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers
+        .get("content-length")
+        .and_then(|len| len.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    if stream.read_exact(&mut body).await.is_err() {
+        return;
+    }
+
+    let reply = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        let reply = route(&mut state, &method, &target, &headers);
+        state.requests.push(MockRequest {
+            method,
+            path: target,
+            status: reply.status,
+        });
+        reply
+    };
+
+    let mut response = format!(
+        "HTTP/1.1 {} Mock\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n",
+        reply.status,
+        reply.content_type,
+        reply.body.len()
+    );
+    for (name, value) in &reply.headers {
+        response.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    response.push_str("\r\n");
+    response.push_str(&reply.body);
+
+    let mut stream = stream.into_inner();
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+fn route(state: &mut MockState, method: &str, target: &str, headers: &HashMap<String, String>) -> Reply {
+    let path = target.split('?').next().unwrap_or_default();
+
+    if let Some(fault) = state.faults.get_mut(path).and_then(VecDeque::pop_front) {
+        let mut reply = Reply::error(fault.status, "injected fault");
+        if let Some(seconds) = fault.retry_after {
+            reply.headers.push(("retry-after", seconds.to_string()));
+        }
+        return reply;
+    }
+
+    match method {
+        "POST" if path == "/authentication/credentials/temporary/ios82" => issue_credentials(state),
+        "GET" if path.starts_with("/suggests/") => suggest(state, path),
+        "GET" if !headers.contains_key("x-amzn-authorization") && is_web_title(path) => {
+            match state.web_titles.contains(path.trim_matches('/').trim_start_matches("title/")) {
+                true => Reply {
+                    status: 200,
+                    content_type: "text/html",
+                    headers: Vec::new(),
+                    body: "<html></html>".to_string(),
+                },
+                false => Reply::error(404, "no such page"),
+            }
+        }
+        "GET" if ["/title/", "/name/", "/chart/"].iter().any(|p| path.starts_with(p)) => {
+            if let Err(message) = verify_signature(state, method, target, headers) {
+                return Reply::error(403, &message);
+            }
+            match state.resources.get(path) {
+                Some(resource) => Reply::json(200, json!({ "resource": resource })),
+                None => Reply::error(404, "no such resource"),
+            }
+        }
+        _ => Reply::error(404, "no such route"),
+    }
+}
+
+fn issue_credentials(state: &mut MockState) -> Reply {
+    state.credentials_issued += 1;
+    let n = state.credentials_issued;
+    let creds = Credentials {
+        access_key_id: format!("MOCKACCESSKEY{}", n),
+        secret_access_key: format!("mock-secret-{}", n),
+        session_token: format!("mock-session-{}", n),
+        expiration: Some(Utc::now() + chrono::Duration::hours(1)),
+    };
+    let reply = Reply::json(
+        200,
+        json!({ "resource": {
+            "@type": "imdb.api.credentials",
+            "accessKeyId": creds.access_key_id,
+            "secretAccessKey": creds.secret_access_key,
+            "sessionToken": creds.session_token,
+            "expirationTimeStamp": creds.expiration.map(|e| e.to_rfc3339()),
+        } }),
+    );
+    state.sessions.insert(creds.session_token.clone(), creds);
+    reply
+}
+
+fn suggest(state: &MockState, path: &str) -> Reply {
+    let Some(query) = path.rsplit('/').next().and_then(|file| file.strip_suffix(".json")) else {
+        return Reply::error(404, "no such route");
+    };
+    let query = urlencoding::decode(query).map_or_else(|_| query.to_string(), |q| q.into_owned());
+    let suggestions = state.suggestions.get(&query).cloned().unwrap_or_else(|| json!([]));
+
+    let payload = json!({ "v": 1, "q": query, "d": suggestions });
+    Reply {
+        status: 200,
+        content_type: "application/javascript",
+        headers: Vec::new(),
+        body: format!("imdb${}({})", query, payload),
+    }
+}
+
+/// `/title/tt0111161/`, as opposed to an API path such as `/title/tt0111161/ratings`
+fn is_web_title(path: &str) -> bool {
+    path.starts_with("/title/") && path.ends_with('/') && path.matches('/').count() == 3
+}
+
+fn verify_signature(
+    state: &MockState,
+    method: &str,
+    target: &str,
+    headers: &HashMap<String, String>,
+) -> std::result::Result<(), String> {
+    let header = |name: &str| headers.get(name).cloned();
+    let (Some(host), Some(x_amz_date), Some(x_amz_security_token), Some(x_amzn_authorization)) = (
+        header("host"),
+        header("x-amz-date"),
+        header("x-amz-security-token"),
+        header("x-amzn-authorization"),
+    ) else {
+        return Err("missing authentication headers".to_string());
+    };
+    let creds = state
+        .sessions
+        .get(&x_amz_security_token)
+        .ok_or("unknown or expired session token")?;
+
+    let request = SigningRequest::new(method, &format!("http://{}{}", host, target)).map_err(|e| e.to_string())?;
+    let headers = AuthHeaders {
+        x_amz_date,
+        x_amz_security_token,
+        x_amzn_authorization,
+    };
+    Signer::verify(&request, &headers, creds).map_err(|e| e.to_string())
+}
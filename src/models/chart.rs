@@ -0,0 +1,158 @@
+use super::common::Image;
+use crate::id::TitleId;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A ranked list of titles (`/chart/*`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawChart")]
+pub struct Chart {
+    pub ranks: Vec<ChartEntry>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One title of a [`Chart`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChartEntry {
+    /// Title ID, e.g. `/title/tt0111161/`
+    pub id: String,
+    /// Position in the chart, starting at 1
+    #[serde(rename = "currentRank", alias = "rank")]
+    pub rank: u32,
+    /// Position in the previous edition of the chart, if the title was in it
+    pub previous_rank: Option<u32>,
+    pub title: Option<String>,
+    pub title_type: Option<String>,
+    pub year: Option<u32>,
+    /// Average user rating, for the top and bottom charts
+    pub chart_rating: Option<f64>,
+    pub image: Option<Image>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// How a [`ChartEntry`] moved since the previous edition of its chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trend {
+    /// Climbed by this many places
+    Up(u32),
+    /// Fell by this many places
+    Down(u32),
+    Unchanged,
+    /// Was not in the previous edition
+    New,
+}
+
+/// Chart as sent by the API; entries of unranked charts get their position
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RawChart {
+    #[serde(alias = "chart", alias = "items")]
+    ranks: Vec<ChartEntry>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl From<RawChart> for Chart {
+    fn from(raw: RawChart) -> Self {
+        let mut ranks = raw.ranks;
+        for (position, entry) in ranks.iter_mut().enumerate() {
+            if entry.rank == 0 {
+                entry.rank = position as u32 + 1;
+            }
+        }
+        Self {
+            ranks,
+            extra: raw.extra,
+        }
+    }
+}
+
+impl Chart {
+    /// The entry of the given title, if it is in the chart
+    pub fn entry(&self, title_id: &TitleId) -> Option<&ChartEntry> {
+        self.ranks
+            .iter()
+            .find(|entry| entry.title_id().as_ref() == Some(title_id))
+    }
+
+    /// The typed IDs of every title in the chart, in chart order
+    pub fn title_ids(&self) -> Vec<TitleId> {
+        self.ranks.iter().filter_map(ChartEntry::title_id).collect()
+    }
+}
+
+impl ChartEntry {
+    /// The typed ID of this title, parsed from `id`
+    pub fn title_id(&self) -> Option<TitleId> {
+        self.id.parse().ok()
+    }
+
+    /// Places gained since the previous edition; negative when the title fell
+    pub fn rank_change(&self) -> Option<i64> {
+        self.previous_rank.map(|previous| i64::from(previous) - i64::from(self.rank))
+    }
+
+    /// Direction and size of the move since the previous edition
+    pub fn trend(&self) -> Trend {
+        match self.rank_change() {
+            None => Trend::New,
+            Some(0) => Trend::Unchanged,
+            Some(change) if change > 0 => Trend::Up(change as u32),
+            Some(change) => Trend::Down(change.unsigned_abs() as u32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_chart_entries_and_trends() {
+        let chart: Chart = serde_json::from_value(json!({
+            "@type": "imdb.api.chart.titlemeter",
+            "ranks": [
+                { "id": "/title/tt0903747/", "currentRank": 1, "previousRank": 4, "title": "Breaking Bad" },
+                { "id": "/title/tt0111161/", "currentRank": 2, "previousRank": 1 },
+                { "id": "/title/tt0944947/", "currentRank": 3, "previousRank": 3 },
+                { "id": "/title/tt0068646/", "currentRank": 4 }
+            ]
+        }))
+        .unwrap();
+
+        let trends: Vec<Trend> = chart.ranks.iter().map(ChartEntry::trend).collect();
+        assert_eq!(
+            trends,
+            [Trend::Up(3), Trend::Down(1), Trend::Unchanged, Trend::New]
+        );
+        assert_eq!(chart.ranks[1].rank_change(), Some(-1));
+        assert_eq!(chart.extra["@type"], "imdb.api.chart.titlemeter");
+
+        let shawshank: TitleId = "tt0111161".parse().unwrap();
+        assert_eq!(chart.entry(&shawshank).unwrap().rank, 2);
+        assert_eq!(chart.title_ids()[0].to_string(), "tt0903747");
+    }
+
+    #[test]
+    fn test_unranked_chart_uses_positions() {
+        let chart: Chart = serde_json::from_value(json!({
+            "chart": [
+                { "id": "/title/tt0111161/", "chartRating": 9.3 },
+                { "id": "/title/tt0068646/", "chartRating": 9.2 }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(chart.ranks[0].rank, 1);
+        assert_eq!(chart.ranks[1].rank, 2);
+        assert_eq!(chart.ranks[1].chart_rating, Some(9.2));
+        assert_eq!(
+            serde_json::from_value::<Chart>(serde_json::to_value(&chart).unwrap()).unwrap(),
+            chart
+        );
+    }
+}
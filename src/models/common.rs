@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// An image hosted by IMDB
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Image {
+    pub id: Option<String>,
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub caption: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A video (trailer, clip, interview, ...) hosted by IMDB
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Video {
+    pub id: String,
+    pub title: Option<String>,
+    pub content_type: Option<String>,
+    pub description: Option<String>,
+    pub duration_seconds: Option<u32>,
+    pub image: Option<Image>,
+    pub encodings: Vec<VideoEncoding>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A playable encoding of a [`Video`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct VideoEncoding {
+    pub definition: Option<String>,
+    pub mime_type: Option<String>,
+    pub play_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Reader votes on how useful or interesting an item is
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct InterestScore {
+    pub usefulness: Option<u32>,
+    pub users: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Kind of title, as reported by search suggestions and `titleType` fields
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TitleKind {
+    Feature,
+    TvSeries,
+    TvMiniSeries,
+    TvEpisode,
+    TvMovie,
+    TvSpecial,
+    Video,
+    VideoGame,
+    Short,
+    Podcast,
+    Other(String),
+}
+
+impl TitleKind {
+    /// Parse the kind label of a search suggestion, e.g. `"TV series"`
+    pub fn from_label(label: &str) -> Self {
+        match label.to_ascii_lowercase().as_str() {
+            "feature" | "movie" => Self::Feature,
+            "tv series" => Self::TvSeries,
+            "tv mini-series" | "tv mini series" => Self::TvMiniSeries,
+            "tv episode" => Self::TvEpisode,
+            "tv movie" => Self::TvMovie,
+            "tv special" => Self::TvSpecial,
+            "video" => Self::Video,
+            "video game" => Self::VideoGame,
+            "short" | "tv short" => Self::Short,
+            "podcast series" | "podcast episode" => Self::Podcast,
+            _ => Self::Other(label.to_string()),
+        }
+    }
+
+    /// Parse a `titleType` field, e.g. `"tvSeries"`
+    pub fn from_title_type(title_type: &str) -> Self {
+        match title_type {
+            "movie" => Self::Feature,
+            "tvSeries" => Self::TvSeries,
+            "tvMiniSeries" => Self::TvMiniSeries,
+            "tvEpisode" => Self::TvEpisode,
+            "tvMovie" => Self::TvMovie,
+            "tvSpecial" => Self::TvSpecial,
+            "video" => Self::Video,
+            "videoGame" => Self::VideoGame,
+            "short" | "tvShort" => Self::Short,
+            "podcastSeries" | "podcastEpisode" => Self::Podcast,
+            _ => Self::Other(title_type.to_string()),
+        }
+    }
+
+    /// Whether this is a series (regular or mini-series)
+    pub fn is_series(&self) -> bool {
+        matches!(self, Self::TvSeries | Self::TvMiniSeries)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::id::TitleId;
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+/// Seasons and episodes of a series (`/title/{imdb_id}/episodes`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Series {
+    pub id: String,
+    pub title: String,
+    pub title_type: Option<String>,
+    pub year: Option<u32>,
+    pub seasons: Vec<Season>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One season of a [`Series`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Season {
+    pub season: u32,
+    pub episodes: Vec<Episode>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One episode of a [`Series`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Episode {
+    /// Episode title ID, e.g. `/title/tt1480055/`
+    pub id: String,
+    pub title: String,
+    pub season: u32,
+    pub episode: u32,
+    pub year: Option<u32>,
+    /// First air date as `YYYY-MM-DD`
+    #[serde(deserialize_with = "release_date")]
+    pub release_date: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Series {
+    /// The season with the given number
+    pub fn season(&self, number: u32) -> Option<&Season> {
+        self.seasons.iter().find(|season| season.season == number)
+    }
+
+    /// Every episode, ordered by season then episode number
+    pub fn episodes(&self) -> Vec<&Episode> {
+        let mut episodes: Vec<&Episode> = self
+            .seasons
+            .iter()
+            .flat_map(|season| &season.episodes)
+            .collect();
+        episodes.sort_by_key(|episode| (episode.season, episode.episode));
+        episodes
+    }
+
+    /// The episode following the one with `episode_id`, across seasons
+    pub fn next_episode(&self, episode_id: &TitleId) -> Option<&Episode> {
+        let episodes = self.episodes();
+        let position = episodes.iter().position(|e| e.is(episode_id))?;
+        episodes.get(position + 1).copied()
+    }
+
+    /// The episode preceding the one with `episode_id`, across seasons
+    pub fn previous_episode(&self, episode_id: &TitleId) -> Option<&Episode> {
+        let episodes = self.episodes();
+        let position = episodes.iter().position(|e| e.is(episode_id))?;
+        episodes.get(position.checked_sub(1)?).copied()
+    }
+
+    /// The most recent episode that aired on or before `today`
+    ///
+    /// Episodes without an air date count as aired if their year is over.
+    pub fn latest_aired_episode(&self, today: NaiveDate) -> Option<&Episode> {
+        self.episodes()
+            .into_iter()
+            .rev()
+            .find(|episode| match (episode.air_date(), episode.year) {
+                (Some(date), _) => date <= today,
+                (None, Some(year)) => i64::from(year) < i64::from(chrono::Datelike::year(&today)),
+                (None, None) => false,
+            })
+    }
+}
+
+impl Season {
+    /// The episode with the given number
+    pub fn episode(&self, number: u32) -> Option<&Episode> {
+        self.episodes.iter().find(|episode| episode.episode == number)
+    }
+}
+
+impl Episode {
+    /// The typed ID of this episode, parsed from `id`
+    pub fn episode_id(&self) -> Option<TitleId> {
+        self.id.parse().ok()
+    }
+
+    /// First air date, if known
+    pub fn air_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.release_date.as_deref()?, "%Y-%m-%d").ok()
+    }
+
+    fn is(&self, id: &TitleId) -> bool {
+        self.episode_id().as_ref() == Some(id)
+    }
+}
+
+/// Accept the release date either as a string or as `{"first": {"date": ...}}`
+fn release_date<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| match value {
+        Value::String(date) => Some(date),
+        Value::Object(fields) => fields
+            .get("first")
+            .unwrap_or(&Value::Null)
+            .get("date")
+            .or_else(|| fields.get("date"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        _ => None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn series() -> Series {
+        serde_json::from_value(json!({
+            "id": "/title/tt0944947/",
+            "title": "Game of Thrones",
+            "titleType": "tvSeries",
+            "seasons": [
+                { "season": 2, "episodes": [
+                    { "id": "/title/tt1971833/", "title": "The North Remembers", "season": 2,
+                      "episode": 1, "year": 2012, "releaseDate": { "first": { "date": "2012-04-01" } } }
+                ] },
+                { "season": 1, "episodes": [
+                    { "id": "/title/tt1668746/", "title": "Fire and Blood", "season": 1,
+                      "episode": 10, "year": 2011, "releaseDate": "2011-06-19" },
+                    { "id": "/title/tt1480055/", "title": "Winter Is Coming", "season": 1,
+                      "episode": 1, "year": 2011, "releaseDate": "2011-04-17" }
+                ] }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_navigation_across_seasons() {
+        let series = series();
+        let finale: TitleId = "tt1668746".parse().unwrap();
+        let premiere: TitleId = "tt1971833".parse().unwrap();
+
+        assert_eq!(series.season(1).unwrap().episode(10).unwrap().title, "Fire and Blood");
+        assert_eq!(series.next_episode(&finale).unwrap().title, "The North Remembers");
+        assert_eq!(series.previous_episode(&premiere).unwrap().title, "Fire and Blood");
+        assert!(series.next_episode(&premiere).is_none());
+    }
+
+    #[test]
+    fn test_latest_aired_episode() {
+        let series = series();
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        assert_eq!(
+            series.latest_aired_episode(date("2011-12-31")).unwrap().title,
+            "Fire and Blood"
+        );
+        assert_eq!(
+            series.latest_aired_episode(date("2012-04-01")).unwrap().air_date(),
+            Some(date("2012-04-01"))
+        );
+        assert!(series.latest_aired_episode(date("2011-01-01")).is_none());
+    }
+}
@@ -0,0 +1,18 @@
+//! Typed response models
+//!
+//! Every model keeps the fields it does not describe in an `extra` map, so
+//! nothing returned by the API is lost when deserializing into a struct.
+
+pub mod chart;
+pub mod common;
+pub mod episode;
+pub mod name;
+pub mod search;
+pub mod title;
+
+pub use chart::*;
+pub use common::{Image, TitleKind, Video};
+pub use episode::*;
+pub use name::*;
+pub use search::*;
+pub use title::*;
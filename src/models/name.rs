@@ -0,0 +1,159 @@
+use super::common::{Image, Video};
+use super::title::Role;
+use crate::id::{NameId, TitleId};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Person information (`/name/{imdb_id}/fulldetails`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Name {
+    pub id: String,
+    pub name: String,
+    pub image: Option<Image>,
+    pub gender: Option<String>,
+    pub real_name: Option<String>,
+    pub birth_date: Option<String>,
+    pub birth_place: Option<String>,
+    pub death_date: Option<String>,
+    pub death_place: Option<String>,
+    pub height_centimeters: Option<f64>,
+    pub akas: Vec<String>,
+    pub nicknames: Vec<String>,
+    pub mini_bios: Vec<MiniBio>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Name {
+    /// The typed ID of this person, parsed from `id`
+    pub fn name_id(&self) -> Option<NameId> {
+        self.id.parse().ok()
+    }
+}
+
+/// A short biography of a person
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MiniBio {
+    pub id: Option<String>,
+    pub author: Option<String>,
+    pub language: Option<String>,
+    pub text: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Person filmography (`/name/{imdb_id}/filmography`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Filmography {
+    pub id: Option<String>,
+    pub filmography: Vec<FilmographyEntry>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One credit in a person's filmography
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FilmographyEntry {
+    /// Title ID, e.g. `/title/tt0372784/`
+    pub id: String,
+    pub title: String,
+    pub title_type: Option<String>,
+    /// Credit category, e.g. `"actor"`, `"director"` or `"writer"`
+    pub category: Option<String>,
+    pub characters: Vec<String>,
+    pub roles: Vec<Role>,
+    pub billing: Option<u32>,
+    pub year: Option<u32>,
+    pub start_year: Option<u32>,
+    pub end_year: Option<u32>,
+    pub episode_count: Option<u32>,
+    /// Production status, e.g. `"released"` or `"filming"`
+    pub status: Option<String>,
+    pub image: Option<Image>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl FilmographyEntry {
+    /// The typed ID of the credited title, parsed from `id`
+    pub fn title_id(&self) -> Option<TitleId> {
+        self.id.parse().ok()
+    }
+
+    /// The years the person was credited on the title
+    ///
+    /// Series credits span `start_year..=end_year` (open-ended while the
+    /// series is running); other credits use the release year.
+    pub fn year_range(&self) -> Option<(u32, Option<u32>)> {
+        match (self.start_year, self.year) {
+            (Some(start), _) => Some((start, self.end_year)),
+            (None, Some(year)) => Some((year, Some(year))),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Person images (`/name/{imdb_id}/images`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NameImages {
+    pub id: Option<String>,
+    pub images: Vec<Image>,
+    pub total_image_count: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Person videos (`/name/{imdb_id}/videos`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NameVideos {
+    pub id: Option<String>,
+    pub videos: Vec<Video>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_filmography_entry_year_range() {
+        let value = json!({
+            "filmography": [
+                { "id": "/title/tt0372784/", "title": "Batman Begins", "category": "actor",
+                  "characters": ["Bruce Wayne"], "year": 2005, "status": "released" },
+                { "id": "/title/tt0108778/", "title": "Friends", "titleType": "tvSeries",
+                  "category": "actor", "startYear": 1994, "endYear": 2004, "episodeCount": 2,
+                  "someNewField": true }
+            ]
+        });
+
+        let filmography: Filmography = serde_json::from_value(value).unwrap();
+        let movie = &filmography.filmography[0];
+        assert_eq!(movie.year_range(), Some((2005, Some(2005))));
+        assert_eq!(movie.characters, vec!["Bruce Wayne"]);
+
+        let series = &filmography.filmography[1];
+        assert_eq!(series.year_range(), Some((1994, Some(2004))));
+        assert_eq!(series.episode_count, Some(2));
+        assert_eq!(series.extra["someNewField"], json!(true));
+    }
+
+    #[test]
+    fn test_mini_bio_keeps_unknown_fields() {
+        let value = json!({ "text": "Born in Memphis.", "author": "IMDb", "userId": "ur0000001" });
+
+        let bio: MiniBio = serde_json::from_value(value).unwrap();
+        assert_eq!(bio.text, "Born in Memphis.");
+        assert_eq!(bio.extra["userId"], "ur0000001");
+        assert_eq!(serde_json::to_value(&bio).unwrap()["userId"], "ur0000001");
+    }
+}
@@ -0,0 +1,231 @@
+use super::common::{Image, TitleKind};
+use crate::error::{ImdbApiError, Result};
+use crate::id::{NameId, TitleId};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// One entry of the search suggestions
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchResult {
+    Title(TitleSuggestion),
+    Name(NameSuggestion),
+    /// Anything else the suggest service returns (companies, keywords, ...)
+    Other(OtherSuggestion),
+}
+
+/// A title suggested by the search
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleSuggestion {
+    pub id: TitleId,
+    pub title: String,
+    pub kind: TitleKind,
+    pub year: Option<u32>,
+    /// Years a series ran, e.g. `"2008-2013"`
+    pub year_range: Option<String>,
+    /// Popularity rank; lower is more popular
+    pub rank: Option<u32>,
+    /// Main cast, e.g. `"Christian Bale, Heath Ledger"`
+    pub starring: Option<String>,
+    pub image: Option<Image>,
+}
+
+/// A person suggested by the search
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameSuggestion {
+    pub id: NameId,
+    pub name: String,
+    /// Profession and best known title, e.g. `"Actor, The Dark Knight (2008)"`
+    pub known_for: Option<String>,
+    /// Popularity rank; lower is more popular
+    pub rank: Option<u32>,
+    pub image: Option<Image>,
+}
+
+/// A suggestion that is neither a title nor a person
+#[derive(Debug, Clone, PartialEq)]
+pub struct OtherSuggestion {
+    pub id: String,
+    pub label: String,
+    pub raw: Value,
+}
+
+/// Suggestion as sent by the suggest service, with its single-letter keys
+#[derive(Deserialize)]
+struct RawSuggestion {
+    id: String,
+    #[serde(default)]
+    l: String,
+    q: Option<String>,
+    s: Option<String>,
+    y: Option<u32>,
+    yr: Option<String>,
+    rank: Option<u32>,
+    i: Option<Value>,
+}
+
+impl SearchResult {
+    /// Decode the suggestions (the `d` array) of a raw search response
+    ///
+    /// An entry that cannot be decoded fails the whole response with
+    /// [`ImdbApiError::UnexpectedPayload`], giving its index and field.
+    pub fn from_suggestions(response: &Value) -> Result<Vec<SearchResult>> {
+        let Some(entries) = response.get("d") else {
+            return Ok(Vec::new());
+        };
+        let entries = entries
+            .as_array()
+            .ok_or_else(|| ImdbApiError::UnexpectedPayload {
+                endpoint: "suggests".to_string(),
+                path: "d".to_string(),
+                message: "expected an array".to_string(),
+            })?;
+
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| Self::from_suggestion(index, entry))
+            .collect()
+    }
+
+    fn from_suggestion(index: usize, value: &Value) -> Result<SearchResult> {
+        let raw: RawSuggestion = serde_path_to_error::deserialize(value.clone()).map_err(|e| {
+            let field = e.path().to_string();
+            ImdbApiError::UnexpectedPayload {
+                endpoint: "suggests".to_string(),
+                path: match field.as_str() {
+                    "." => format!("d[{}]", index),
+                    _ => format!("d[{}].{}", index, field),
+                },
+                message: e.into_inner().to_string(),
+            }
+        })?;
+        let image = raw.i.as_ref().and_then(parse_image);
+
+        if let Ok(id) = raw.id.parse::<TitleId>() {
+            return Ok(SearchResult::Title(TitleSuggestion {
+                id,
+                title: raw.l,
+                kind: TitleKind::from_label(raw.q.as_deref().unwrap_or_default()),
+                year: raw.y,
+                year_range: raw.yr,
+                rank: raw.rank,
+                starring: raw.s,
+                image,
+            }));
+        }
+
+        if let Ok(id) = raw.id.parse::<NameId>() {
+            return Ok(SearchResult::Name(NameSuggestion {
+                id,
+                name: raw.l,
+                known_for: raw.s,
+                rank: raw.rank,
+                image,
+            }));
+        }
+
+        Ok(SearchResult::Other(OtherSuggestion {
+            id: raw.id,
+            label: raw.l,
+            raw: value.clone(),
+        }))
+    }
+}
+
+/// Parse a suggestion image, given either as `[url, width, height]` or as
+/// `{"imageUrl", "width", "height"}`
+fn parse_image(value: &Value) -> Option<Image> {
+    let (url, width, height) = match value {
+        Value::Array(parts) => (parts.first()?, parts.get(1), parts.get(2)),
+        Value::Object(fields) => (
+            fields.get("imageUrl")?,
+            fields.get("width"),
+            fields.get("height"),
+        ),
+        _ => return None,
+    };
+    let dimension = |v: Option<&Value>| v.and_then(Value::as_u64).and_then(|n| u32::try_from(n).ok());
+
+    Some(Image {
+        id: None,
+        url: url.as_str()?.to_string(),
+        width: dimension(width),
+        height: dimension(height),
+        caption: None,
+        extra: Map::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::ImdbId;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_suggestions() {
+        let response = json!({
+            "v": 1,
+            "q": "the_dark_knight",
+            "d": [
+                { "l": "The Dark Knight", "id": "tt0468569", "q": "feature", "y": 2008,
+                  "s": "Christian Bale, Heath Ledger", "rank": 36,
+                  "i": ["https://m.media-amazon.com/images/M/dk.jpg", 1383, 2048] },
+                { "l": "Christian Bale", "id": "nm0000288", "s": "Actor, The Dark Knight (2008)",
+                  "i": { "imageUrl": "https://m.media-amazon.com/images/M/cb.jpg", "width": 400, "height": 600 } },
+                { "l": "Batman: The Animated Series", "id": "tt0103359", "q": "TV series",
+                  "y": 1992, "yr": "1992-1995" },
+                { "l": "dark knight", "id": "/search/keyword/?keywords=dark-knight" }
+            ]
+        });
+
+        let results = SearchResult::from_suggestions(&response).unwrap();
+        assert_eq!(results.len(), 4);
+
+        let SearchResult::Title(movie) = &results[0] else {
+            panic!("expected a title");
+        };
+        assert_eq!(movie.id.as_str(), "tt0468569");
+        assert_eq!(movie.kind, TitleKind::Feature);
+        assert_eq!(movie.rank, Some(36));
+        assert_eq!(movie.image.as_ref().unwrap().width, Some(1383));
+
+        let SearchResult::Name(person) = &results[1] else {
+            panic!("expected a name");
+        };
+        assert_eq!(person.name, "Christian Bale");
+        assert_eq!(person.image.as_ref().unwrap().height, Some(600));
+
+        let SearchResult::Title(series) = &results[2] else {
+            panic!("expected a title");
+        };
+        assert_eq!(series.kind, TitleKind::TvSeries);
+        assert_eq!(series.year_range.as_deref(), Some("1992-1995"));
+
+        assert!(matches!(results[3], SearchResult::Other(_)));
+    }
+
+    #[test]
+    fn test_malformed_entry_is_reported() {
+        let response = json!({
+            "d": [
+                { "l": "The Dark Knight", "id": "tt0468569", "y": 2008 },
+                { "l": "The Dark Knight Rises", "id": "tt1345836", "y": "2012" }
+            ]
+        });
+
+        let err = SearchResult::from_suggestions(&response).unwrap_err();
+        assert!(matches!(
+            &err,
+            ImdbApiError::UnexpectedPayload { endpoint, path, .. } if endpoint == "suggests" && path == "d[1].y"
+        ));
+
+        let err = SearchResult::from_suggestions(&json!({ "d": [{ "l": "no id" }] })).unwrap_err();
+        assert!(matches!(&err, ImdbApiError::UnexpectedPayload { path, .. } if path == "d[0]"));
+    }
+
+    #[test]
+    fn test_empty_response() {
+        assert!(SearchResult::from_suggestions(&json!({ "v": 1 })).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,610 @@
+use super::common::{Image, InterestScore, Video};
+use crate::id::{NameId, TitleId};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// The short title summary embedded in many responses
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleBase {
+    pub id: String,
+    pub title: String,
+    pub title_type: Option<String>,
+    pub year: Option<u32>,
+    pub image: Option<Image>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TitleBase {
+    /// The typed ID of this title, parsed from `id`
+    pub fn title_id(&self) -> Option<TitleId> {
+        self.id.parse().ok()
+    }
+}
+
+/// Title information (`/title/{imdb_id}/auxiliary`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Title {
+    pub id: String,
+    pub title: String,
+    pub title_type: Option<String>,
+    pub year: Option<u32>,
+    pub running_time_in_minutes: Option<u32>,
+    pub image: Option<Image>,
+    pub genres: Vec<String>,
+    pub release_date: Option<String>,
+    pub plot: Option<PlotSummary>,
+    pub ratings: Option<Rating>,
+    pub principals: Vec<Credit>,
+    /// The series an episode belongs to
+    pub parent_title: Option<TitleBase>,
+    /// Season number, for episodes
+    pub season: Option<u32>,
+    /// Episode number within the season, for episodes
+    pub episode: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Title {
+    /// The typed ID of this title, parsed from `id`
+    pub fn title_id(&self) -> Option<TitleId> {
+        self.id.parse().ok()
+    }
+}
+
+/// Aggregate user rating of a title
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Rating {
+    pub rating: Option<f64>,
+    pub rating_count: Option<u64>,
+    pub can_rate: Option<bool>,
+    pub top_rank: Option<u32>,
+    pub bottom_rank: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title ratings (`/title/{imdb_id}/ratings`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleRatings {
+    pub id: String,
+    pub title: String,
+    pub title_type: Option<String>,
+    pub year: Option<u32>,
+    pub rating: Option<f64>,
+    pub rating_count: Option<u64>,
+    pub can_rate: Option<bool>,
+    pub top_rank: Option<u32>,
+    pub bottom_rank: Option<u32>,
+    /// Histograms keyed by demographic (e.g. `"IMDb Users"`, `"Females"`)
+    pub ratings_histograms: HashMap<String, RatingsHistogram>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Rating distribution for one demographic
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RatingsHistogram {
+    pub aggregate_rating: Option<f64>,
+    pub demographic: Option<String>,
+    /// Vote count per rating, keyed by the rating (`"1"` to `"10"`)
+    pub histogram: HashMap<String, u64>,
+    pub total_ratings: Option<u64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title credits (`/title/{imdb_id}/fullcredits`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleCredits {
+    pub base: Option<TitleBase>,
+    pub cast: Vec<Credit>,
+    /// Crew members keyed by department (e.g. `"director"`, `"writer"`)
+    pub crew: HashMap<String, Vec<Credit>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A person credited on a title
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Credit {
+    pub id: String,
+    pub name: String,
+    pub category: Option<String>,
+    pub job: Option<String>,
+    pub characters: Vec<String>,
+    pub roles: Vec<Role>,
+    pub attr: Vec<String>,
+    pub image: Option<Image>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Credit {
+    /// The typed ID of the credited person, parsed from `id`
+    pub fn name_id(&self) -> Option<NameId> {
+        self.id.parse().ok()
+    }
+}
+
+/// A character played by a cast member
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Role {
+    pub character: Option<String>,
+    pub character_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title genres (`/title/{imdb_id}/genres`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleGenres {
+    pub id: String,
+    pub title: String,
+    pub title_type: Option<String>,
+    pub year: Option<u32>,
+    pub genres: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title plot outline and summaries (`/title/{imdb_id}/plot`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PlotSummary {
+    pub id: Option<String>,
+    pub outline: Option<PlotText>,
+    pub summaries: Vec<PlotText>,
+    pub total_summaries: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single plot outline, summary or synopsis
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PlotText {
+    pub id: Option<String>,
+    pub author: Option<String>,
+    pub text: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title plot synopsis (`/title/{imdb_id}/plotsynopsis`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PlotSynopsis {
+    pub id: Option<String>,
+    pub base: Option<TitleBase>,
+    pub plot_synopses: Vec<PlotText>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title awards (`/title/{imdb_id}/awards`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Awards {
+    pub id: Option<String>,
+    pub awards: Vec<Award>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single award nomination or win
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Award {
+    pub award_name: Option<String>,
+    pub category: Option<String>,
+    pub event_id: Option<String>,
+    pub event_name: Option<String>,
+    pub instance_within_year: Option<u32>,
+    pub is_winner: bool,
+    pub year: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title release dates (`/title/{imdb_id}/releases`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Releases {
+    pub id: Option<String>,
+    pub releases: Vec<Release>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A release of a title in one region
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Release {
+    pub date: Option<String>,
+    pub region: Option<String>,
+    pub premiere: bool,
+    pub wide: bool,
+    pub attributes: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title trivia (`/title/{imdb_id}/trivia`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Trivia {
+    pub id: Option<String>,
+    pub trivia: Vec<TriviaItem>,
+    pub spoiler_trivia: Vec<TriviaItem>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single trivia entry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TriviaItem {
+    pub id: Option<String>,
+    pub text: String,
+    pub interest_score: Option<InterestScore>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title goofs (`/title/{imdb_id}/goofs`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Goofs {
+    pub id: Option<String>,
+    pub goofs: Vec<Goof>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single goof
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Goof {
+    pub id: Option<String>,
+    /// Goof category, e.g. `"continuity"` or `"factual error"`
+    #[serde(rename = "type")]
+    pub goof_type: Option<String>,
+    pub text: String,
+    pub interest_score: Option<InterestScore>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title quotes (`/title/{imdb_id}/quotes`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Quotes {
+    pub id: Option<String>,
+    pub quotes: Vec<Quote>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A quote, made of one or more lines of dialogue
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Quote {
+    pub id: Option<String>,
+    pub lines: Vec<QuoteLine>,
+    pub interest_score: Option<InterestScore>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single line of a [`Quote`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct QuoteLine {
+    pub characters: Vec<Role>,
+    pub text: Option<String>,
+    pub stage_direction: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title soundtracks (`/title/{imdb_id}/soundtracks`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Soundtracks {
+    pub id: Option<String>,
+    pub soundtracks: Vec<Soundtrack>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A song used in a title
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Soundtrack {
+    pub id: Option<String>,
+    pub name: String,
+    pub comment: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title technical specifications (`/title/{imdb_id}/technical`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleTechnical {
+    pub id: Option<String>,
+    pub runtimes: Vec<Runtime>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A running time, optionally qualified (e.g. `"director's cut"`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Runtime {
+    pub time_in_minutes: Option<u32>,
+    pub attributes: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Companies involved with a title (`/title/{imdb_id}/companies`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleCompanies {
+    pub id: Option<String>,
+    pub distribution: Vec<CompanyCredit>,
+    pub production: Vec<CompanyCredit>,
+    pub special_effects: Vec<CompanyCredit>,
+    pub other: Vec<CompanyCredit>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A company credited on a title
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CompanyCredit {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub attributes: Vec<String>,
+    pub regions: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title connections (`/title/{imdb_id}/connections`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Connections {
+    pub id: Option<String>,
+    pub connections: Vec<Connection>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A relationship to another title, e.g. `"follows"` or `"referenced in"`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Connection {
+    pub category: Option<String>,
+    pub title: Option<TitleBase>,
+    pub description: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Titles similar to a title (`/title/{imdb_id}/similarities`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Similarities {
+    pub id: Option<String>,
+    pub similarities: Vec<TitleBase>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title versions and alternate titles (`/title/{imdb_id}/versions`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleVersions {
+    pub id: Option<String>,
+    pub alternate_titles: Vec<AlternateTitle>,
+    pub origins: Vec<String>,
+    pub spoken_languages: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A title as known in another region or language
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AlternateTitle {
+    pub title: String,
+    pub region: Option<String>,
+    pub language: Option<String>,
+    pub types: Vec<String>,
+    pub attributes: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// User reviews of a title (`/title/{imdb_id}/userreviews`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserReviews {
+    pub id: Option<String>,
+    pub reviews: Vec<UserReview>,
+    pub total_reviews: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single user review
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserReview {
+    pub review_id: Option<String>,
+    pub review_title: Option<String>,
+    pub review_text: String,
+    pub author_rating: Option<u32>,
+    pub submission_date: Option<String>,
+    pub spoiler: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Metacritic reviews of a title (`/title/{imdb_id}/metacritic`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MetacriticReviews {
+    pub id: Option<String>,
+    pub metascore: Option<u32>,
+    pub review_count: Option<u32>,
+    pub user_score: Option<f64>,
+    pub user_rating_count: Option<u32>,
+    pub reviews: Vec<MetacriticReview>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single critic review aggregated by Metacritic
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MetacriticReview {
+    pub review_site: Option<String>,
+    pub reviewer: Option<String>,
+    pub score: Option<u32>,
+    pub quote: Option<String>,
+    pub review_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title news (`/title/{imdb_id}/news`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleNews {
+    pub id: Option<String>,
+    pub items: Vec<NewsItem>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single news article
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NewsItem {
+    pub id: Option<String>,
+    pub head: String,
+    pub body: Option<String>,
+    pub link: Option<String>,
+    pub publish_date_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title images (`/title/{imdb_id}/images`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleImages {
+    pub id: Option<String>,
+    pub images: Vec<Image>,
+    pub total_image_count: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Title videos (`/title/{imdb_id}/videos`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TitleVideos {
+    pub id: Option<String>,
+    pub videos: Vec<Video>,
+    pub size: Option<u32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::ImdbId;
+    use serde_json::json;
+
+    #[test]
+    fn test_title_deserializes_and_keeps_unknown_fields() {
+        let value = json!({
+            "@type": "imdb.api.title.auxiliary",
+            "id": "/title/tt0111161/",
+            "title": "The Shawshank Redemption",
+            "titleType": "movie",
+            "year": 1994,
+            "runningTimeInMinutes": 142,
+            "genres": ["Drama"],
+            "ratings": { "rating": 9.3, "ratingCount": 2900000, "otherRanks": [] },
+            "principals": [
+                { "id": "/name/nm0000209/", "name": "Tim Robbins", "category": "actor",
+                  "characters": ["Andy Dufresne"] }
+            ],
+            "certificate": { "certificate": "R" }
+        });
+
+        let title: Title = serde_json::from_value(value).unwrap();
+        assert_eq!(title.title, "The Shawshank Redemption");
+        assert_eq!(title.title_id().unwrap().as_str(), "tt0111161");
+        assert_eq!(title.year, Some(1994));
+        let ratings = title.ratings.unwrap();
+        assert_eq!(ratings.rating, Some(9.3));
+        assert!(ratings.extra.contains_key("otherRanks"));
+        assert_eq!(title.principals[0].characters, vec!["Andy Dufresne"]);
+        assert!(title.extra.contains_key("certificate"));
+        assert!(title.extra.contains_key("@type"));
+    }
+
+    #[test]
+    fn test_nested_models_round_trip_unknown_fields() {
+        let histogram = json!({ "aggregateRating": 9.3, "histogram": { "10": 5 }, "source": "users" });
+        let value: RatingsHistogram = serde_json::from_value(histogram.clone()).unwrap();
+        assert_eq!(value.extra["source"], "users");
+        assert_eq!(serde_json::to_value(&value).unwrap()["source"], "users");
+
+        let role = json!({ "character": "Andy Dufresne", "endYear": 1994 });
+        let value: Role = serde_json::from_value(role).unwrap();
+        assert_eq!(serde_json::to_value(&value).unwrap()["endYear"], 1994);
+
+        let plot = json!({ "text": "Two imprisoned men bond.", "language": "en" });
+        let value: PlotText = serde_json::from_value(plot).unwrap();
+        assert_eq!(serde_json::to_value(&value).unwrap()["language"], "en");
+
+        let score = json!({ "usefulness": 10, "users": 12, "ratio": 0.8 });
+        let value: InterestScore = serde_json::from_value(score).unwrap();
+        assert_eq!(serde_json::to_value(&value).unwrap()["ratio"], 0.8);
+    }
+
+    #[test]
+    fn test_credits_crew_keyed_by_department() {
+        let value = json!({
+            "cast": [{ "id": "/name/nm0000209/", "name": "Tim Robbins" }],
+            "crew": {
+                "director": [{ "id": "/name/nm0001104/", "name": "Frank Darabont", "job": "director" }]
+            }
+        });
+
+        let credits: TitleCredits = serde_json::from_value(value).unwrap();
+        assert_eq!(credits.cast.len(), 1);
+        assert_eq!(credits.crew["director"][0].name, "Frank Darabont");
+    }
+}
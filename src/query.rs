@@ -0,0 +1,125 @@
+use crate::error::{ImdbApiError, Result};
+use unicode_normalization::UnicodeNormalization;
+
+/// Normalize a search query into the form used by the suggest service
+///
+/// Latin letters lose their diacritics (`Amélie` becomes `amelie`) and a few
+/// ligatures are expanded (`ß` becomes `ss`). Letters from other scripts,
+/// such as Japanese or Cyrillic, are kept as they are. Apostrophes are
+/// dropped, hyphens and dots are kept between words (`spider-man`), and
+/// whitespace and other punctuation become single underscores.
+///
+/// Returns [`ImdbApiError::EmptySearchQuery`] if nothing searchable is left.
+///
+/// ```
+/// use imdb_api::normalize_search_query;
+///
+/// assert_eq!(normalize_search_query("Se7en: Director's Cut").unwrap(), "se7en_directors_cut");
+/// assert_eq!(normalize_search_query("Amélie").unwrap(), "amelie");
+/// assert!(normalize_search_query(" ?! ").is_err());
+/// ```
+pub fn normalize_search_query(query: &str) -> Result<String> {
+    let mut normalized = String::with_capacity(query.len());
+
+    for c in query.nfkc() {
+        match fold(c) {
+            Folded::Text(text) => normalized.extend(text.chars().flat_map(char::to_lowercase)),
+            Folded::Char(c) => normalized.extend(c.to_lowercase()),
+            Folded::Joiner(c) if normalized.ends_with(char::is_alphanumeric) => normalized.push(c),
+            Folded::Drop => {}
+            Folded::Joiner(_) | Folded::Separator => {
+                // A joiner only joins when a word follows (`mr. robot`)
+                if normalized.ends_with(JOINERS) {
+                    normalized.pop();
+                }
+                if !normalized.is_empty() && !normalized.ends_with('_') {
+                    normalized.push('_');
+                }
+            }
+        }
+    }
+
+    let normalized = normalized.trim_end_matches(|c| c == '_' || JOINERS.contains(&c)).to_string();
+    if normalized.chars().any(char::is_alphanumeric) {
+        Ok(normalized)
+    } else {
+        Err(ImdbApiError::EmptySearchQuery(query.to_string()))
+    }
+}
+
+const JOINERS: [char; 3] = ['-', '.', '~'];
+
+enum Folded {
+    Char(char),
+    Text(&'static str),
+    /// Kept inside words (`spider-man`), a separator elsewhere
+    Joiner(char),
+    Drop,
+    Separator,
+}
+
+fn fold(c: char) -> Folded {
+    match c {
+        '\'' | '’' | 'ʼ' | '‘' | '`' => Folded::Drop,
+        c if JOINERS.contains(&c) => Folded::Joiner(c),
+        'ß' => Folded::Text("ss"),
+        'æ' | 'Æ' => Folded::Text("ae"),
+        'œ' | 'Œ' => Folded::Text("oe"),
+        'ø' | 'Ø' => Folded::Char('o'),
+        'ł' | 'Ł' => Folded::Char('l'),
+        'đ' | 'Đ' | 'ð' | 'Ð' => Folded::Char('d'),
+        'þ' | 'Þ' => Folded::Text("th"),
+        c if c.is_alphanumeric() => {
+            // Strip diacritics from Latin letters only: in other scripts the
+            // combining marks change the letter (e.g. が is not か).
+            let mut decomposed = std::iter::once(c).nfd();
+            match decomposed.next() {
+                Some(base) if base.is_ascii_alphanumeric() => Folded::Char(base),
+                _ => Folded::Char(c),
+            }
+        }
+        _ => Folded::Separator,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_search_query() {
+        let cases = [
+            ("The Dark Knight", "the_dark_knight"),
+            ("Amélie", "amelie"),
+            ("Se7en: Director's Cut", "se7en_directors_cut"),
+            ("  Crouching   Tiger, Hidden Dragon ", "crouching_tiger_hidden_dragon"),
+            ("Spider-Man", "spider-man"),
+            ("Mission - Impossible", "mission_impossible"),
+            ("Mr. Robot", "mr_robot"),
+            ("Spider- Man", "spider_man"),
+            ("E.T.", "e.t"),
+            ("Who Framed Roger Rabbit?-", "who_framed_roger_rabbit"),
+            ("Das Boot / Die Straße", "das_boot_die_strasse"),
+            ("Ænima Łódź", "aenima_lodz"),
+            ("千と千尋の神隠し", "千と千尋の神隠し"),
+            ("ガンダム", "ガンダム"),
+            ("Брат 2", "брат_2"),
+            ("ＡＢＣ", "abc"),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(normalize_search_query(query).unwrap(), expected, "{}", query);
+        }
+    }
+
+    #[test]
+    fn test_empty_queries_are_rejected() {
+        for query in ["", "   ", "?!", "'''", "--"] {
+            assert!(
+                matches!(normalize_search_query(query), Err(ImdbApiError::EmptySearchQuery(_))),
+                "{:?}",
+                query
+            );
+        }
+    }
+}
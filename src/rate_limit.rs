@@ -0,0 +1,177 @@
+use crate::error::{ImdbApiError, Result};
+use crate::transport::Sleep;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Client-side limits on how fast requests are sent
+///
+/// Requests are paced by a token bucket that refills at
+/// `requests_per_second` and holds at most `burst` tokens, and at most
+/// `max_concurrency` requests are in flight at once. The limits are shared by
+/// every clone of an [`ImdbClient`](crate::ImdbClient).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    /// Sustained request rate
+    pub requests_per_second: f64,
+    /// Number of requests that may be sent back to back after a quiet period
+    pub burst: u32,
+    /// Maximum number of requests in flight
+    pub max_concurrency: usize,
+}
+
+impl RateLimit {
+    /// No client-side limits
+    pub fn unlimited() -> Self {
+        Self {
+            requests_per_second: f64::INFINITY,
+            burst: u32::MAX,
+            max_concurrency: Semaphore::MAX_PERMITS,
+        }
+    }
+
+    /// Check that `requests_per_second` is positive, or infinite for no limit
+    pub fn validate(&self) -> Result<()> {
+        if self.requests_per_second > 0.0 {
+            Ok(())
+        } else {
+            Err(ImdbApiError::InvalidRateLimit(format!(
+                "requests_per_second must be positive, got {}",
+                self.requests_per_second
+            )))
+        }
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 5.0,
+            burst: 5,
+            max_concurrency: 8,
+        }
+    }
+}
+
+/// Token bucket plus concurrency limit enforcing a [`RateLimit`]
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+    in_flight: Semaphore,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative when callers have reserved future tokens
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        let in_flight = Semaphore::new(limit.max_concurrency.clamp(1, Semaphore::MAX_PERMITS));
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(limit.burst),
+                refilled_at: Instant::now(),
+            }),
+            in_flight,
+            limit,
+        }
+    }
+
+    /// Wait until a request may be sent
+    ///
+    /// The returned permit counts towards `max_concurrency` until dropped.
+    pub async fn acquire(&self, sleep: &dyn Sleep) -> SemaphorePermit<'_> {
+        let permit = self
+            .in_flight
+            .acquire()
+            .await
+            .expect("rate limiter semaphore is never closed");
+
+        let wait = self.reserve_token();
+        if !wait.is_zero() {
+            sleep.sleep(wait).await;
+        }
+
+        permit
+    }
+
+    /// Take a token, returning how long to wait until it is actually available
+    fn reserve_token(&self) -> Duration {
+        let rate = self.limit.requests_per_second;
+        // Invalid rates are rejected when the client is built; never pace on them.
+        if rate.is_infinite() || rate.is_nan() || rate <= 0.0 {
+            return Duration::ZERO;
+        }
+
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled_at).as_secs_f64() * rate;
+        bucket.tokens = (bucket.tokens + refill).min(f64::from(self.limit.burst.max(1)));
+        bucket.refilled_at = now;
+
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::try_from_secs_f64(-bucket.tokens / rate).unwrap_or(Duration::ZERO)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::TokioSleep;
+
+    #[tokio::test]
+    async fn test_requests_are_paced_after_burst() {
+        let limiter = RateLimiter::new(RateLimit {
+            requests_per_second: 20.0,
+            burst: 2,
+            max_concurrency: 4,
+        });
+
+        let start = Instant::now();
+        for _ in 0..6 {
+            drop(limiter.acquire(&TokioSleep).await);
+        }
+
+        // 2 requests from the burst, then 4 more at 50ms intervals.
+        assert!(start.elapsed() >= Duration::from_millis(190));
+    }
+
+    #[test]
+    fn test_non_positive_rate_is_rejected() {
+        for requests_per_second in [0.0, -1.0, f64::NAN] {
+            let limit = RateLimit {
+                requests_per_second,
+                ..RateLimit::default()
+            };
+            assert!(matches!(limit.validate(), Err(ImdbApiError::InvalidRateLimit(_))));
+            assert!(crate::ImdbClient::builder().rate_limit(limit.clone()).build().is_err());
+
+            // The limiter itself never panics on such a rate.
+            let limiter = RateLimiter::new(limit);
+            for _ in 0..10 {
+                assert_eq!(limiter.reserve_token(), Duration::ZERO);
+            }
+        }
+        assert!(RateLimit::unlimited().validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unlimited_does_not_wait() {
+        let limiter = RateLimiter::new(RateLimit::unlimited());
+
+        let start = Instant::now();
+        for _ in 0..100 {
+            drop(limiter.acquire(&TokioSleep).await);
+        }
+
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,373 @@
+//! Parse media release names and filenames
+//!
+//! ```
+//! use imdb_api::release::{parse_release, ReleaseSource};
+//!
+//! let release = parse_release("Show.Name.S02E05.1080p.WEB-DL.mkv");
+//! assert_eq!(release.title, "Show Name");
+//! assert_eq!((release.season, release.episode), (Some(2), Some(5)));
+//! assert_eq!(release.resolution.as_deref(), Some("1080p"));
+//! assert_eq!(release.source, Some(ReleaseSource::WebDl));
+//! ```
+
+use crate::models::TitleKind;
+use chrono::Datelike;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// File extensions stripped from release names
+const EXTENSIONS: &[&str] = &[
+    "mkv", "mp4", "m4v", "avi", "mov", "wmv", "mpg", "mpeg", "ts", "m2ts", "webm", "flv", "iso",
+    "srt", "sub", "idx", "nfo",
+];
+
+/// Tags that end the title of a release without a year or episode
+const TAGS: &[&str] = &[
+    "proper", "repack", "internal", "extended", "unrated", "remastered", "limited", "uncut",
+    "x264", "x265", "h264", "h265", "hevc", "avc", "xvid", "divx", "10bit", "hdr", "hdr10",
+    "dv", "sdr", "dts", "aac", "ac3", "dd5", "ddp5", "atmos", "truehd", "multi", "dubbed",
+    "subbed",
+];
+
+/// Where a release was sourced from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReleaseSource {
+    BluRay,
+    Remux,
+    WebDl,
+    WebRip,
+    Hdtv,
+    Dvd,
+    Screener,
+    Telesync,
+    Cam,
+}
+
+impl ReleaseSource {
+    fn from_token(token: &str) -> Option<Self> {
+        let source = match token.to_ascii_lowercase().as_str() {
+            "bluray" | "blu-ray" | "bdrip" | "brrip" | "bd" => Self::BluRay,
+            "remux" => Self::Remux,
+            "web-dl" | "webdl" | "web" => Self::WebDl,
+            "webrip" | "web-rip" => Self::WebRip,
+            "hdtv" | "pdtv" | "sdtv" | "hdtvrip" => Self::Hdtv,
+            "dvd" | "dvdrip" | "dvd-rip" | "dvdr" | "dvd5" | "dvd9" => Self::Dvd,
+            "screener" | "dvdscr" | "scr" => Self::Screener,
+            "ts" | "telesync" | "hdts" => Self::Telesync,
+            "cam" | "camrip" | "hdcam" => Self::Cam,
+            _ => return None,
+        };
+        Some(source)
+    }
+}
+
+/// What could be recognized in a release name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedRelease {
+    /// Title with separators turned into spaces, e.g. `"Show Name"`
+    pub title: String,
+    pub year: Option<u32>,
+    pub season: Option<u32>,
+    pub episode: Option<u32>,
+    /// Vertical resolution such as `"1080p"`; `4K` and `UHD` become `"2160p"`
+    pub resolution: Option<String>,
+    pub source: Option<ReleaseSource>,
+}
+
+impl ParsedRelease {
+    /// Whether the release looks like a TV episode or season
+    pub fn is_episode(&self) -> bool {
+        self.season.is_some() || self.episode.is_some()
+    }
+
+    /// The kind of title the release most likely is, if it can be told
+    pub fn kind_hint(&self) -> Option<TitleKind> {
+        self.is_episode().then_some(TitleKind::TvSeries)
+    }
+
+    /// The query to pass to [`ImdbClient::search`](crate::ImdbClient::search)
+    pub fn search_query(&self) -> &str {
+        &self.title
+    }
+}
+
+enum Marker {
+    Year(u32),
+    Episode(Option<u32>, Option<u32>),
+    /// Anything else that is unlikely to be part of the title
+    Tag,
+}
+
+fn episode_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?i)^s(\d{1,2})(?:[-_]?e(\d{1,3})(?:-?e?\d{1,3})*)?$|^(\d{1,2})x(\d{2,3})$")
+            .unwrap()
+    })
+}
+
+fn resolution_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)^(\d{3,4})[pi]$").unwrap())
+}
+
+fn resolution(token: &str) -> Option<String> {
+    if token.eq_ignore_ascii_case("4k") || token.eq_ignore_ascii_case("uhd") {
+        return Some("2160p".to_string());
+    }
+    resolution_regex()
+        .captures(token)
+        .map(|caps| format!("{}p", &caps[1]))
+}
+
+fn year(token: &str) -> Option<u32> {
+    let max_year = u32::try_from(chrono::Utc::now().year()).unwrap_or(u32::MAX) + 1;
+    let year: u32 = token.parse().ok().filter(|_| token.len() == 4)?;
+    (1880..=max_year).contains(&year).then_some(year)
+}
+
+/// Classify the token at `i`, looking ahead for `Season 2 Episode 5`
+fn marker(tokens: &[&str], i: usize) -> Option<Marker> {
+    let token = tokens[i];
+
+    if let Some(caps) = episode_regex().captures(token) {
+        let number = |n: usize| caps.get(n).and_then(|m| m.as_str().parse().ok());
+        return Some(match caps.get(1) {
+            Some(_) => Marker::Episode(number(1), number(2)),
+            None => Marker::Episode(number(3), number(4)),
+        });
+    }
+
+    let next_number = || tokens.get(i + 1).and_then(|t| t.parse::<u32>().ok());
+    if token.eq_ignore_ascii_case("season") {
+        if let Some(season) = next_number() {
+            let episode = tokens
+                .get(i + 2)
+                .filter(|t| t.eq_ignore_ascii_case("episode"))
+                .and_then(|_| tokens.get(i + 3))
+                .and_then(|t| t.parse().ok());
+            return Some(Marker::Episode(Some(season), episode));
+        }
+    }
+    if token.eq_ignore_ascii_case("episode") {
+        if let Some(episode) = next_number() {
+            return Some(Marker::Episode(None, Some(episode)));
+        }
+    }
+
+    if let Some(year) = year(token) {
+        return Some(Marker::Year(year));
+    }
+
+    // Release groups are glued to the last tag, e.g. `x264-GROUP`; a
+    // hyphenated word only ends the title if what precedes the group is a tag.
+    if is_tag(token) || token.rsplit_once('-').is_some_and(|(tag, _)| is_tag(tag)) {
+        return Some(Marker::Tag);
+    }
+
+    None
+}
+
+/// Whether a whole token is a resolution, a source or a release tag
+fn is_tag(token: &str) -> bool {
+    resolution(token).is_some()
+        || ReleaseSource::from_token(token).is_some()
+        || TAGS.contains(&token.to_ascii_lowercase().as_str())
+}
+
+/// Strip directories, the extension and a leading `[Group]` tag
+fn base_name(name: &str) -> &str {
+    let mut name = name.rsplit(['/', '\\']).next().unwrap_or(name).trim();
+
+    if let Some((stem, extension)) = name.rsplit_once('.') {
+        if EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()) {
+            name = stem;
+        }
+    }
+
+    if name.starts_with('[') {
+        if let Some(end) = name.find(']') {
+            name = &name[end + 1..];
+        }
+    }
+    name
+}
+
+/// Parse a release name or media filename
+///
+/// The title is everything before the release year or the season/episode
+/// marker. Without either, it ends at the first resolution, source or release
+/// tag, so such words can still be part of a title (`Uncut.Gems.2019`). When
+/// several years appear, the last one is the release year, so
+/// `2012.2009.1080p` is the film "2012" from 2009.
+pub fn parse_release(name: &str) -> ParsedRelease {
+    let spaced: String = base_name(name)
+        .chars()
+        .map(|c| match c {
+            '.' | '_' | '(' | ')' | '[' | ']' | '{' | '}' => ' ',
+            c => c,
+        })
+        .collect();
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+    let markers: Vec<Option<Marker>> = (0..tokens.len()).map(|i| marker(&tokens, i)).collect();
+
+    let mut release = ParsedRelease::default();
+
+    // Tag and source words can be title words (`Uncut.Gems`, `Charlottes.Web`),
+    // so the title ends at the year or season/episode marker when there is
+    // one, and only otherwise at the first tag. A year in first position is
+    // part of the title; the last year before the episode is the release year.
+    let first_episode = markers
+        .iter()
+        .position(|m| matches!(m, Some(Marker::Episode(..))))
+        .unwrap_or(tokens.len());
+    let year_at = (1..first_episode)
+        .rev()
+        .find(|&i| matches!(markers[i], Some(Marker::Year(_))));
+    if let Some(Some(Marker::Year(year))) = year_at.map(|i| &markers[i]) {
+        release.year = Some(*year);
+    }
+    let title_end = match (year_at, first_episode < tokens.len()) {
+        (Some(year_at), _) => year_at,
+        (None, true) => first_episode,
+        (None, false) => (1..tokens.len())
+            .find(|&i| matches!(markers[i], Some(Marker::Tag)))
+            .unwrap_or(tokens.len()),
+    };
+
+    release.title = tokens[..title_end]
+        .join(" ")
+        .trim_end_matches([' ', '-', ':', ','])
+        .to_string();
+
+    for (i, marker) in markers.iter().enumerate().skip(title_end) {
+        if let Some(Marker::Episode(season, episode)) = marker {
+            release.season = release.season.or(*season);
+            release.episode = release.episode.or(*episode);
+        }
+        let token = tokens[i];
+        if release.resolution.is_none() {
+            release.resolution = resolution(token);
+        }
+        if release.source.is_none() {
+            // Release groups are glued to the last tag, e.g. `WEB-DL-GROUP`.
+            release.source = ReleaseSource::from_token(token).or_else(|| {
+                token
+                    .rsplit_once('-')
+                    .and_then(|(source, _)| ReleaseSource::from_token(source))
+            });
+        }
+    }
+
+    release
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Case {
+        name: &'static str,
+        title: &'static str,
+        year: Option<u32>,
+        season: Option<u32>,
+        episode: Option<u32>,
+        resolution: Option<&'static str>,
+        source: Option<ReleaseSource>,
+    }
+
+    const fn movie(
+        name: &'static str,
+        title: &'static str,
+        year: Option<u32>,
+        resolution: Option<&'static str>,
+        source: Option<ReleaseSource>,
+    ) -> Case {
+        Case {
+            name,
+            title,
+            year,
+            season: None,
+            episode: None,
+            resolution,
+            source,
+        }
+    }
+
+    const fn episode(
+        name: &'static str,
+        title: &'static str,
+        season: Option<u32>,
+        episode: Option<u32>,
+        resolution: Option<&'static str>,
+        source: Option<ReleaseSource>,
+    ) -> Case {
+        Case {
+            name,
+            title,
+            year: None,
+            season,
+            episode,
+            resolution,
+            source,
+        }
+    }
+
+    use ReleaseSource::*;
+
+    const CASES: &[Case] = &[
+        movie("Movie Title (2010) [BluRay].mp4", "Movie Title", Some(2010), None, Some(BluRay)),
+        movie("The.Matrix.1999.1080p.BluRay.x264-GROUP.mkv", "The Matrix", Some(1999), Some("1080p"), Some(BluRay)),
+        movie("The Matrix (1999)", "The Matrix", Some(1999), None, None),
+        movie("2012.2009.720p.BRRip.x264.mkv", "2012", Some(2009), Some("720p"), Some(BluRay)),
+        movie("1917.2019.2160p.UHD.BluRay.REMUX.HDR.mkv", "1917", Some(2019), Some("2160p"), Some(BluRay)),
+        movie("Blade.Runner.2049.2017.1080p.WEB-DL.mkv", "Blade Runner 2049", Some(2017), Some("1080p"), Some(WebDl)),
+        movie("Blade Runner 2049.mkv", "Blade Runner 2049", None, None, None),
+        movie("2001 A Space Odyssey 1968 4K.mkv", "2001 A Space Odyssey", Some(1968), Some("2160p"), None),
+        movie("Amélie.2001.DVDRip.XviD.avi", "Amélie", Some(2001), None, Some(Dvd)),
+        movie("Se7en (1995) [1080p]", "Se7en", Some(1995), Some("1080p"), None),
+        movie("Some_Movie_2015_CAM.avi", "Some Movie", Some(2015), None, Some(Cam)),
+        movie("Dune.Part.Two.2024.PROPER.1080p.WEBRip.mkv", "Dune Part Two", Some(2024), Some("1080p"), Some(WebRip)),
+        movie("/media/movies/Heat (1995)/Heat.1995.Remastered.720p.mkv", "Heat", Some(1995), Some("720p"), None),
+        movie("Alien.1979.Directors.Cut.1080p.WEB-DL-GROUP", "Alien", Some(1979), Some("1080p"), Some(WebDl)),
+        movie("Untitled", "Untitled", None, None, None),
+        movie("Uncut.Gems.2019.1080p.WEB-DL.mkv", "Uncut Gems", Some(2019), Some("1080p"), Some(WebDl)),
+        movie("Charlottes.Web.1973.720p.mkv", "Charlottes Web", Some(1973), Some("720p"), None),
+        movie("Cam.2018.1080p.WEBRip.x264-GRP", "Cam", Some(2018), Some("1080p"), Some(WebRip)),
+        movie("Limited.Edition.1080p.x264", "Limited Edition", None, Some("1080p"), None),
+        movie("The.Dvorak-Project.2015.720p.mkv", "The Dvorak-Project", Some(2015), Some("720p"), None),
+        movie("Webster-Jones.Hdx-Files.1080p.x264-GRP", "Webster-Jones Hdx-Files", None, Some("1080p"), None),
+        episode("Show.Name.S02E05.1080p.WEB-DL.mkv", "Show Name", Some(2), Some(5), Some("1080p"), Some(WebDl)),
+        episode("The.Office.US.S01E01.720p.HDTV.x264-LOL.mkv", "The Office US", Some(1), Some(1), Some("720p"), Some(Hdtv)),
+        episode("game.of.thrones.s01e09.hdtv.xvid-fqm.avi", "game of thrones", Some(1), Some(9), None, Some(Hdtv)),
+        episode("Game of Thrones - 1x05 - The Wolf and the Lion.avi", "Game of Thrones", Some(1), Some(5), None, None),
+        episode("Sherlock.S02.1080p.BluRay.x264", "Sherlock", Some(2), None, Some("1080p"), Some(BluRay)),
+        episode("Friends.S05E14E15.DVDRip.mkv", "Friends", Some(5), Some(14), None, Some(Dvd)),
+        episode("Show Name Season 2 Episode 5.mp4", "Show Name", Some(2), Some(5), None, None),
+        episode("[SubGroup] Anime Title S01E03 [1080p].mkv", "Anime Title", Some(1), Some(3), Some("1080p"), None),
+        episode("Mr.Robot.S04E13.720p.WEBRip.mkv", "Mr Robot", Some(4), Some(13), Some("720p"), Some(WebRip)),
+        episode("The.Limited.Series.S01E01", "The Limited Series", Some(1), Some(1), None, None),
+    ];
+
+    #[test]
+    fn test_parse_release_table() {
+        for case in CASES {
+            let release = parse_release(case.name);
+            assert_eq!(release.title, case.title, "title of {}", case.name);
+            assert_eq!(release.season, case.season, "season of {}", case.name);
+            assert_eq!(release.episode, case.episode, "episode of {}", case.name);
+            assert_eq!(release.resolution.as_deref(), case.resolution, "resolution of {}", case.name);
+            assert_eq!(release.source, case.source, "source of {}", case.name);
+            assert_eq!(release.year, case.year, "year of {}", case.name);
+        }
+    }
+
+    #[test]
+    fn test_year_before_episode() {
+        let release = parse_release("Doctor.Who.2005.S10E01.720p.HDTV.mkv");
+        assert_eq!(release.title, "Doctor Who");
+        assert_eq!(release.year, Some(2005));
+        assert_eq!((release.season, release.episode), (Some(10), Some(1)));
+        assert_eq!(release.kind_hint(), Some(TitleKind::TvSeries));
+    }
+}
@@ -0,0 +1,216 @@
+//! Resolve free-form titles such as `"The Matrix (1999)"` to IMDB IDs
+//!
+//! Candidates come from the search suggestions and are ranked by a
+//! confidence score combining title similarity, year proximity and kind.
+
+use crate::client::ImdbClient;
+use crate::error::Result;
+use crate::id::TitleId;
+use crate::models::{TitleKind, TitleSuggestion};
+use crate::query::normalize_search_query;
+use crate::release::{parse_release, ParsedRelease};
+
+/// Weight of the title similarity in the confidence score
+const TITLE_WEIGHT: f64 = 0.6;
+/// Weight of the year proximity in the confidence score
+const YEAR_WEIGHT: f64 = 0.25;
+/// Weight of the kind match in the confidence score
+const KIND_WEIGHT: f64 = 0.15;
+
+/// A candidate title with how confident the match is
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleMatch {
+    pub id: TitleId,
+    pub title: String,
+    pub year: Option<u32>,
+    pub kind: TitleKind,
+    /// Overall confidence, between 0 and 1
+    pub confidence: f64,
+}
+
+/// Split a query such as `"The Matrix (1999)"` or `"The.Matrix.1999"` into
+/// the title and the year
+///
+/// Only a year or season/episode marker ends the title: without one, the
+/// whole query is the title, tag words such as `Web` included. See
+/// [`parse_release`] for the full release-name parser.
+pub fn parse_title_query(query: &str) -> (String, Option<u32>) {
+    let release = parse_release(query);
+    match (release.year.is_some() || release.is_episode()) && !release.title.is_empty() {
+        true => (release.title, release.year),
+        false => (query.trim().to_string(), None),
+    }
+}
+
+/// Score how well a suggestion matches the wanted title, year and kind
+pub fn score_candidate(
+    title: &str,
+    year: Option<u32>,
+    kind: Option<&TitleKind>,
+    candidate: &TitleSuggestion,
+) -> f64 {
+    let title_score = match (comparable(title), comparable(&candidate.title)) {
+        (Some(wanted), Some(found)) => strsim::normalized_damerau_levenshtein(&wanted, &found),
+        _ => 0.0,
+    };
+
+    let year_score = match (year, candidate.year) {
+        (Some(wanted), Some(found)) => match wanted.abs_diff(found) {
+            0 => 1.0,
+            1 => 0.7,
+            2 => 0.3,
+            _ => 0.0,
+        },
+        _ => 0.5,
+    };
+
+    let kind_score = match kind {
+        None => 1.0,
+        Some(wanted) if *wanted == candidate.kind => 1.0,
+        Some(wanted) if is_movie_like(wanted) && is_movie_like(&candidate.kind) => 0.5,
+        Some(wanted) if wanted.is_series() && candidate.kind.is_series() => 0.5,
+        Some(_) => 0.0,
+    };
+
+    TITLE_WEIGHT * title_score + YEAR_WEIGHT * year_score + KIND_WEIGHT * kind_score
+}
+
+fn comparable(title: &str) -> Option<String> {
+    normalize_search_query(title)
+        .ok()
+        .map(|normalized| normalized.replace(['_', '-', '.', '~'], " "))
+}
+
+fn is_movie_like(kind: &TitleKind) -> bool {
+    matches!(kind, TitleKind::Feature | TitleKind::TvMovie | TitleKind::Video)
+}
+
+impl ImdbClient {
+    /// Resolve a free-form title to ranked IMDB title candidates
+    ///
+    /// `query` may carry the year itself (`"The Matrix (1999)"`); an explicit
+    /// `year` takes precedence. A query that is already an IMDB ID or URL
+    /// resolves to that title with full confidence.
+    ///
+    /// ```no_run
+    /// use imdb_api::ImdbClient;
+    /// use imdb_api::models::TitleKind;
+    ///
+    /// # async fn run() -> imdb_api::Result<()> {
+    /// let client = ImdbClient::new();
+    /// let matches = client
+    ///     .resolve_title("The Matrix (1999)", None, Some(TitleKind::Feature))
+    ///     .await?;
+    /// if let Some(best) = matches.first() {
+    ///     println!("{} ({:.2})", best.id, best.confidence);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_title(
+        &self,
+        query: &str,
+        year: Option<u32>,
+        kind: Option<TitleKind>,
+    ) -> Result<Vec<TitleMatch>> {
+        if let Ok(id) = query.parse::<TitleId>() {
+            let title = self.get_title_typed(&id).await?;
+            return Ok(vec![TitleMatch {
+                id,
+                title: title.title,
+                year: title.year,
+                kind: TitleKind::from_title_type(title.title_type.as_deref().unwrap_or_default()),
+                confidence: 1.0,
+            }]);
+        }
+
+        let (title, parsed_year) = parse_title_query(query);
+        let year = year.or(parsed_year);
+
+        let mut matches: Vec<TitleMatch> = self
+            .search_titles(&title, &[])
+            .await?
+            .into_iter()
+            .map(|candidate| TitleMatch {
+                confidence: score_candidate(&title, year, kind.as_ref(), &candidate),
+                id: candidate.id,
+                title: candidate.title,
+                year: candidate.year,
+                kind: candidate.kind,
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        Ok(matches)
+    }
+
+    /// Resolve a parsed release name, such as a media filename
+    ///
+    /// Releases with a season or episode are matched against series.
+    pub async fn resolve_release(&self, release: &ParsedRelease) -> Result<Vec<TitleMatch>> {
+        self.resolve_title(release.search_query(), release.year, release.kind_hint())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(id: &str, title: &str, year: u32, kind: TitleKind) -> TitleSuggestion {
+        TitleSuggestion {
+            id: id.parse().unwrap(),
+            title: title.to_string(),
+            kind,
+            year: Some(year),
+            year_range: None,
+            rank: None,
+            starring: None,
+            image: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_title_query() {
+        let cases = [
+            ("The Matrix (1999)", "The Matrix", Some(1999)),
+            ("The.Matrix.1999", "The Matrix", Some(1999)),
+            ("Blade Runner 2049 (2017)", "Blade Runner 2049", Some(2017)),
+            ("2001: A Space Odyssey", "2001: A Space Odyssey", None),
+            ("1917", "1917", None),
+            ("Amélie", "Amélie", None),
+            ("Uncut Gems (2019)", "Uncut Gems", Some(2019)),
+            ("Charlotte's Web", "Charlotte's Web", None),
+            ("Cam", "Cam", None),
+            ("The Limited 1080p", "The Limited 1080p", None),
+            ("S.W.A.T. (2017)", "S W A T", Some(2017)),
+            ("Season 2", "Season 2", None),
+        ];
+
+        for (query, title, year) in cases {
+            assert_eq!(parse_title_query(query), (title.to_string(), year), "{}", query);
+        }
+    }
+
+    #[test]
+    fn test_exact_match_ranks_first() {
+        let matrix = suggestion("tt0133093", "The Matrix", 1999, TitleKind::Feature);
+        let reloaded = suggestion("tt0234215", "The Matrix Reloaded", 2003, TitleKind::Feature);
+        let series = suggestion("tt0106062", "Matrix", 1993, TitleKind::TvSeries);
+        let kind = Some(&TitleKind::Feature);
+
+        let best = score_candidate("The Matrix", Some(1999), kind, &matrix);
+        assert!((best - 1.0).abs() < 1e-9);
+        assert!(best > score_candidate("The Matrix", Some(1999), kind, &reloaded));
+        assert!(best > score_candidate("The Matrix", Some(1999), kind, &series));
+    }
+
+    #[test]
+    fn test_year_proximity() {
+        let candidate = suggestion("tt0133093", "The Matrix", 1999, TitleKind::Feature);
+        let exact = score_candidate("The Matrix", Some(1999), None, &candidate);
+        let close = score_candidate("The Matrix", Some(2000), None, &candidate);
+        let far = score_candidate("The Matrix", Some(2010), None, &candidate);
+        assert!(exact > close && close > far);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::transport::HttpResponse;
+use std::time::Duration;
+
+/// Policy for retrying requests that failed with a transient error
+///
+/// Connection errors, timeouts, `429 Too Many Requests` and `5xx` responses
+/// are retried with exponential backoff and full jitter. A `Retry-After`
+/// header sent by the server takes precedence over the computed backoff.
+/// No retry is attempted once `max_elapsed` would be exceeded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled for each following one
+    pub initial_backoff: Duration,
+    /// Upper bound for a single backoff
+    pub max_backoff: Duration,
+    /// Upper bound for the total time spent on one request, retries included
+    pub max_elapsed: Duration,
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retry number `retry` (starting at 0), with full jitter
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let ceiling = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+        ceiling.mul_f64(fastrand::f64())
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_elapsed: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Whether a response status is worth retrying
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
+/// Parse a `Retry-After` header, given either in seconds or as an HTTP date
+pub(crate) fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    let value = response.header("retry-after")?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let delay = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(delay.to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(250),
+            ..RetryPolicy::default()
+        };
+
+        for retry in 0..40 {
+            let limit = if retry == 0 { 100 } else { 250 };
+            assert!(policy.backoff(retry) <= Duration::from_millis(limit));
+        }
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let response = HttpResponse::new(429, "");
+        assert_eq!(retry_after(&response), None);
+
+        let response = response.with_header("Retry-After", "120");
+        assert_eq!(retry_after(&response), Some(Duration::from_secs(120)));
+
+        let response = HttpResponse::new(503, "").with_header("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT");
+        assert_eq!(retry_after(&response), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_retryable_statuses() {
+        assert!(is_retryable_status(429));
+        assert!(is_retryable_status(503));
+        assert!(!is_retryable_status(404));
+        assert!(!is_retryable_status(403));
+    }
+}
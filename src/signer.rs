@@ -3,6 +3,8 @@ use base64::Engine;
 use chrono::{DateTime, Utc};
 use hmac::{Hmac, Mac};
 use sha2::{Digest, Sha256};
+use std::fmt;
+use std::sync::Arc;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -12,6 +14,21 @@ pub struct Credentials {
     pub access_key_id: String,
     pub secret_access_key: String,
     pub session_token: String,
+    /// When the temporary credentials stop being accepted, if the API reported it
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl Credentials {
+    /// Whether the credentials expire within `margin` from now
+    ///
+    /// Credentials without a known expiration are treated as valid until the
+    /// API rejects them.
+    pub fn expires_within(&self, margin: chrono::Duration) -> bool {
+        match self.expiration {
+            Some(expiration) => expiration - margin <= Utc::now(),
+            None => false,
+        }
+    }
 }
 
 /// Authentication headers returned by the signer
@@ -22,47 +39,126 @@ pub struct AuthHeaders {
     pub x_amzn_authorization: String,
 }
 
-/// AWS3-style request signer
-pub struct Signer;
+/// Source of the time used to date signatures
+pub trait Clock: Send + Sync + fmt::Debug {
+    fn now(&self) -> DateTime<Utc>;
+}
 
-impl Signer {
-    /// Sign a request URL with the provided credentials
-    pub fn sign(url: &str, creds: &Credentials) -> Result<AuthHeaders> {
-        let parsed_url = url::Url::parse(url)
-            .map_err(|e| ImdbApiError::ApiError(format!("Invalid URL: {}", e)))?;
+/// The system clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
 
-        let headers = Self::get_auth_headers(&parsed_url, creds)?;
-        Ok(headers)
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
     }
+}
 
-    fn get_auth_headers(url: &url::Url, creds: &Credentials) -> Result<AuthHeaders> {
-        let now: DateTime<Utc> = Utc::now();
-        let amz_date = now.format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+/// A clock stopped at a given time, for reproducible signatures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
 
-        let string_to_sign = format!(
-            "GET\n{}\n\nhost:api.imdbws.com\nx-amz-date:{}\nx-amz-security-token:{}\n\n",
-            url.path(),
-            amz_date,
-            creds.session_token
-        );
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
 
-        // Compute SHA-256 hash of the string to sign
-        let hash = sha2::Sha256::digest(string_to_sign.as_bytes());
+/// The parts of a request covered by its signature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningRequest {
+    /// HTTP method, e.g. `GET`
+    pub method: String,
+    /// Host as sent in the `Host` header, with the port unless it is the default
+    pub host: String,
+    pub path: String,
+    /// Canonical query string: pairs sorted and percent-encoded, joined by `&`
+    pub query: String,
+}
 
-        // Compute HMAC-SHA256
-        let mut mac =
-            HmacSha256::new_from_slice(creds.secret_access_key.as_bytes())
-                .map_err(|_| ImdbApiError::AuthenticationError)?;
-        mac.update(&hash);
-        let hmac_result = mac.finalize().into_bytes();
+impl SigningRequest {
+    /// Describe a request for `url`
+    pub fn new(method: &str, url: &str) -> Result<Self> {
+        let invalid = |reason: String| ImdbApiError::InvalidUrl {
+            url: url.to_string(),
+            reason,
+        };
+        let url = url::Url::parse(url).map_err(|e| invalid(e.to_string()))?;
+        let host = url.host_str().ok_or_else(|| invalid("no host".to_string()))?;
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        };
+
+        Ok(Self {
+            method: method.to_ascii_uppercase(),
+            host,
+            path: url.path().to_string(),
+            query: canonical_query(url.query().unwrap_or_default()),
+        })
+    }
+
+    /// Describe a `GET` request for `url`
+    pub fn get(url: &str) -> Result<Self> {
+        Self::new("GET", url)
+    }
+
+    /// The string whose hash is signed
+    pub fn string_to_sign(&self, amz_date: &str, session_token: &str) -> String {
+        format!(
+            "{}\n{}\n{}\nhost:{}\nx-amz-date:{}\nx-amz-security-token:{}\n\n",
+            self.method, self.path, self.query, self.host, amz_date, session_token
+        )
+    }
+}
 
-        // Base64 encode the HMAC result
-        let b64_hmac = base64::engine::general_purpose::STANDARD.encode(&hmac_result);
+/// Sort the pairs of a query string and percent-encode them uniformly
+fn canonical_query(query: &str) -> String {
+    let mut pairs: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+        .filter(|(name, value)| !name.is_empty() || !value.is_empty())
+        .map(|(name, value)| (urlencoding::encode(&name).into_owned(), urlencoding::encode(&value).into_owned()))
+        .collect();
+    pairs.sort();
+    pairs
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// AWS3-style request signer
+#[derive(Debug, Clone)]
+pub struct Signer {
+    clock: Arc<dyn Clock>,
+}
+
+impl Signer {
+    /// A signer dating signatures with the system clock
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+
+    /// A signer dating signatures with `clock`
+    pub fn with_clock(clock: impl Clock + 'static) -> Self {
+        Self {
+            clock: Arc::new(clock),
+        }
+    }
+
+    /// Sign a `GET` request URL with the provided credentials
+    pub fn sign(url: &str, creds: &Credentials) -> Result<AuthHeaders> {
+        Self::new().sign_request(&SigningRequest::get(url)?, creds)
+    }
+
+    /// Sign a request with the provided credentials, dated by the clock
+    pub fn sign_request(&self, request: &SigningRequest, creds: &Credentials) -> Result<AuthHeaders> {
+        let amz_date = self.clock.now().format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+        let signature = Self::signature(request, &amz_date, creds)?;
 
         // Build authorization header
         let authorization = format!(
             "AWS3 AWSAccessKeyId={},Algorithm=HmacSHA256,SignedHeaders=Host;X-Amz-Date;X-Amz-Security-Token,Signature={}",
-            creds.access_key_id, b64_hmac
+            creds.access_key_id, signature
         );
 
         Ok(AuthHeaders {
@@ -71,11 +167,151 @@ impl Signer {
             x_amzn_authorization: authorization,
         })
     }
+
+    /// Base64 signature of `request`, dated `amz_date`
+    pub fn signature(request: &SigningRequest, amz_date: &str, creds: &Credentials) -> Result<String> {
+        let mac = Self::mac(request, amz_date, creds)?;
+        Ok(base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes()))
+    }
+
+    /// Check that `headers` carry a valid signature of `request` by `creds`
+    ///
+    /// The signature is compared in constant time. The date is not checked
+    /// against the current time; that is up to the caller.
+    pub fn verify(request: &SigningRequest, headers: &AuthHeaders, creds: &Credentials) -> Result<()> {
+        let invalid = |reason: &str| Err(ImdbApiError::InvalidSignature(reason.to_string()));
+
+        let Some(fields) = headers.x_amzn_authorization.strip_prefix("AWS3 ") else {
+            return invalid("not an AWS3 authorization");
+        };
+        let field = |name: &str| {
+            fields
+                .split(',')
+                .filter_map(|field| field.split_once('='))
+                .find(|(key, _)| key.trim() == name)
+                .map(|(_, value)| value.trim())
+        };
+
+        if field("AWSAccessKeyId") != Some(creds.access_key_id.as_str()) {
+            return invalid("unknown access key");
+        }
+        if headers.x_amz_security_token != creds.session_token {
+            return invalid("unknown session token");
+        }
+        let Some(signature) = field("Signature")
+            .and_then(|s| base64::engine::general_purpose::STANDARD.decode(s).ok())
+        else {
+            return invalid("malformed signature");
+        };
+
+        Self::mac(request, &headers.x_amz_date, creds)?
+            .verify_slice(&signature)
+            .or_else(|_| invalid("signature does not match"))
+    }
+
+    fn mac(request: &SigningRequest, amz_date: &str, creds: &Credentials) -> Result<HmacSha256> {
+        let string_to_sign = request.string_to_sign(amz_date, &creds.session_token);
+
+        // The HMAC covers the SHA-256 of the string to sign
+        let hash = Sha256::digest(string_to_sign.as_bytes());
+        let mut mac = HmacSha256::new_from_slice(creds.secret_access_key.as_bytes())
+            .map_err(|_| ImdbApiError::AuthenticationError)?;
+        mac.update(&hash);
+        Ok(mac)
+    }
+}
+
+impl Default for Signer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+
+    fn test_creds() -> Credentials {
+        Credentials {
+            access_key_id: "test_key".to_string(),
+            secret_access_key: "test_secret".to_string(),
+            session_token: "test_token".to_string(),
+            expiration: None,
+        }
+    }
+
+    fn test_signer() -> Signer {
+        Signer::with_clock(FixedClock(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()))
+    }
+
+    #[test]
+    fn test_known_answer_vectors() {
+        let creds = test_creds();
+        let vectors = [
+            ("GET", "https://api.imdbws.com/title/tt0111161/auxiliary", "3kU6PkshrGXXYjBWA8Ch8j++1IeJje0w+AkJjHst5l0="),
+            ("GET", "http://127.0.0.1:8080/chart/top?b=two+words&a=1", "+dwT+cTBzUSDkS5Jfu+y2HL9WWDWwHX/8J5Gr+aBunE="),
+            ("POST", "https://api.imdbws.com:443/title/tt0111161/auxiliary", "tNnJsl/NOodeRi7uxbBW28y7wF3d8g8h945XErclI+0="),
+        ];
+
+        for (method, url, signature) in vectors {
+            let request = SigningRequest::new(method, url).unwrap();
+            let headers = test_signer().sign_request(&request, &creds).unwrap();
+            assert_eq!(headers.x_amz_date, "Mon, 01 Jan 2024 00:00:00 GMT");
+            assert_eq!(
+                headers.x_amzn_authorization,
+                format!(
+                    "AWS3 AWSAccessKeyId=test_key,Algorithm=HmacSHA256,SignedHeaders=Host;X-Amz-Date;X-Amz-Security-Token,Signature={}",
+                    signature
+                ),
+                "{} {}",
+                method,
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_signing_request_is_canonical() {
+        let request = SigningRequest::new("get", "http://127.0.0.1:8080/chart/top?b=two%20words&a=1&").unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.host, "127.0.0.1:8080");
+        assert_eq!(request.path, "/chart/top");
+        assert_eq!(request.query, "a=1&b=two%20words");
+        assert_eq!(SigningRequest::get("https://api.imdbws.com/").unwrap().host, "api.imdbws.com");
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        let err = SigningRequest::get("not a url").unwrap_err();
+        assert!(matches!(err, ImdbApiError::InvalidUrl { url, .. } if url == "not a url"));
+        assert!(matches!(SigningRequest::get("data:text/plain,x"), Err(ImdbApiError::InvalidUrl { .. })));
+    }
+
+    #[test]
+    fn test_verify() {
+        let creds = test_creds();
+        let request = SigningRequest::get("https://api.imdbws.com/title/tt0111161/auxiliary").unwrap();
+        let headers = test_signer().sign_request(&request, &creds).unwrap();
+        assert!(Signer::verify(&request, &headers, &creds).is_ok());
+
+        let other_host = SigningRequest { host: "imdb.test".to_string(), ..request.clone() };
+        let other_date = AuthHeaders { x_amz_date: "Tue, 02 Jan 2024 00:00:00 GMT".to_string(), ..headers.clone() };
+        let other_secret = Credentials { secret_access_key: "other".to_string(), ..creds.clone() };
+        let other_key = Credentials { access_key_id: "other".to_string(), ..creds.clone() };
+        for (request, headers, creds) in [
+            (&other_host, &headers, &creds),
+            (&request, &other_date, &creds),
+            (&request, &headers, &other_secret),
+            (&request, &headers, &other_key),
+        ] {
+            let err = Signer::verify(request, headers, creds).unwrap_err();
+            assert!(matches!(err, ImdbApiError::InvalidSignature(_)));
+        }
+
+        let forged = AuthHeaders { x_amzn_authorization: "AWS3 AWSAccessKeyId=test_key,Signature=Zm9yZ2Vk".to_string(), ..headers };
+        assert!(Signer::verify(&request, &forged, &creds).is_err());
+    }
 
     #[test]
     fn test_signer_creates_headers() {
@@ -83,6 +319,7 @@ mod tests {
             access_key_id: "test_key".to_string(),
             secret_access_key: "test_secret".to_string(),
             session_token: "test_token".to_string(),
+            expiration: None,
         };
 
         let result = Signer::sign("https://api.imdbws.com/test", &creds);
@@ -93,4 +330,19 @@ mod tests {
         assert!(!headers.x_amz_security_token.is_empty());
         assert!(!headers.x_amzn_authorization.is_empty());
     }
+
+    #[test]
+    fn test_credentials_expires_within() {
+        let mut creds = Credentials {
+            access_key_id: "test_key".to_string(),
+            secret_access_key: "test_secret".to_string(),
+            session_token: "test_token".to_string(),
+            expiration: None,
+        };
+        assert!(!creds.expires_within(chrono::Duration::minutes(5)));
+
+        creds.expiration = Some(Utc::now() + chrono::Duration::minutes(2));
+        assert!(creds.expires_within(chrono::Duration::minutes(5)));
+        assert!(!creds.expires_within(chrono::Duration::seconds(30)));
+    }
 }
@@ -0,0 +1,374 @@
+//! Chart history: timestamped snapshots and the differences between them
+
+use crate::client::ImdbClient;
+use crate::endpoint::{Endpoint, EndpointKind};
+use crate::error::{ImdbApiError, Result};
+use crate::models::{Chart, ChartEntry};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// A chart as it was at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartSnapshot {
+    /// Path of the chart endpoint, e.g. `/chart/moviemeter`
+    pub path: String,
+    pub taken_at: DateTime<Utc>,
+    pub chart: Chart,
+}
+
+/// Storage for chart snapshots
+///
+/// Unlike a [`Cache`](crate::Cache), a store is the only copy of the history,
+/// so its errors are reported.
+pub trait ChartStore: Send + Sync + fmt::Debug {
+    /// Add a snapshot to the history of its chart
+    fn save(&self, snapshot: &ChartSnapshot) -> Result<()>;
+
+    /// Every snapshot of the chart at `path`, oldest first
+    fn load(&self, path: &str) -> Result<Vec<ChartSnapshot>>;
+}
+
+/// Store keeping one JSON file per snapshot, in a directory per chart
+///
+/// Files are named after the snapshot time, e.g.
+/// `moviemeter/20240107T090000.000Z.json`.
+#[derive(Debug, Clone)]
+pub struct JsonChartStore {
+    dir: PathBuf,
+}
+
+impl JsonChartStore {
+    /// Create a store in `dir`, which is created if missing
+    pub fn new(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn chart_dir(&self, path: &str) -> PathBuf {
+        let name = path.trim_start_matches("/chart/").replace(['/', '\\'], "_");
+        self.dir.join(name)
+    }
+}
+
+impl ChartStore for JsonChartStore {
+    fn save(&self, snapshot: &ChartSnapshot) -> Result<()> {
+        let dir = self.chart_dir(&snapshot.path);
+        std::fs::create_dir_all(&dir)?;
+
+        let name = snapshot.taken_at.format("%Y%m%dT%H%M%S%.3fZ");
+        let path = dir.join(format!("{}.json", name));
+        let tmp = path.with_extension(format!("{}.tmp", fastrand::u64(..)));
+        std::fs::write(&tmp, serde_json::to_vec_pretty(snapshot)?)?;
+        std::fs::rename(&tmp, &path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
+        })?;
+        Ok(())
+    }
+
+    fn load(&self, path: &str) -> Result<Vec<ChartSnapshot>> {
+        let dir = self.chart_dir(path);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<_>>()?;
+        files.retain(|file| file.extension().is_some_and(|ext| ext == "json"));
+        files.sort();
+
+        let mut snapshots = Vec::with_capacity(files.len());
+        for file in files {
+            snapshots.push(serde_json::from_slice(&std::fs::read(file)?)?);
+        }
+        Ok(snapshots)
+    }
+}
+
+/// In-memory store, for tests and short-lived processes
+#[derive(Debug, Default)]
+pub struct MemoryChartStore {
+    snapshots: Mutex<HashMap<String, Vec<ChartSnapshot>>>,
+}
+
+impl MemoryChartStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ChartStore for MemoryChartStore {
+    fn save(&self, snapshot: &ChartSnapshot) -> Result<()> {
+        let mut snapshots = self.snapshots.lock().unwrap_or_else(|e| e.into_inner());
+        let history = snapshots.entry(snapshot.path.clone()).or_default();
+        let at = history.partition_point(|s| s.taken_at <= snapshot.taken_at);
+        history.insert(at, snapshot.clone());
+        Ok(())
+    }
+
+    fn load(&self, path: &str) -> Result<Vec<ChartSnapshot>> {
+        let snapshots = self.snapshots.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(snapshots.get(path).cloned().unwrap_or_default())
+    }
+}
+
+/// A title that changed rank between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartMove {
+    /// The entry in the newer snapshot
+    pub entry: ChartEntry,
+    pub previous_rank: u32,
+    /// Places gained; negative when the title fell
+    pub rank_change: i64,
+}
+
+/// What changed in a chart between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartDiff {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Titles in the newer snapshot only, in chart order
+    pub new_entries: Vec<ChartEntry>,
+    /// Titles in the older snapshot only, in their old chart order
+    pub dropped_entries: Vec<ChartEntry>,
+    /// Titles in both snapshots whose rank changed, biggest moves first
+    pub movers: Vec<ChartMove>,
+}
+
+impl ChartDiff {
+    /// Compare two snapshots of the same chart
+    pub fn between(old: &ChartSnapshot, new: &ChartSnapshot) -> Self {
+        let old_ranks: HashMap<String, &ChartEntry> =
+            old.chart.ranks.iter().map(|entry| (entry_key(entry), entry)).collect();
+        let new_keys: Vec<String> = new.chart.ranks.iter().map(entry_key).collect();
+
+        let mut new_entries = Vec::new();
+        let mut movers = Vec::new();
+        for (entry, key) in new.chart.ranks.iter().zip(&new_keys) {
+            match old_ranks.get(key) {
+                None => new_entries.push(entry.clone()),
+                Some(previous) if previous.rank != entry.rank => movers.push(ChartMove {
+                    entry: entry.clone(),
+                    previous_rank: previous.rank,
+                    rank_change: i64::from(previous.rank) - i64::from(entry.rank),
+                }),
+                Some(_) => {}
+            }
+        }
+        movers.sort_by_key(|m| (Reverse(m.rank_change.unsigned_abs()), m.entry.rank));
+
+        let dropped_entries = old
+            .chart
+            .ranks
+            .iter()
+            .filter(|entry| !new_keys.contains(&entry_key(entry)))
+            .cloned()
+            .collect();
+
+        Self {
+            from: old.taken_at,
+            to: new.taken_at,
+            new_entries,
+            dropped_entries,
+            movers,
+        }
+    }
+
+    /// The `n` titles that moved the most, in either direction
+    pub fn biggest_movers(&self, n: usize) -> &[ChartMove] {
+        &self.movers[..n.min(self.movers.len())]
+    }
+
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.new_entries.is_empty() && self.dropped_entries.is_empty() && self.movers.is_empty()
+    }
+}
+
+/// Entries are matched by title ID, whatever form the API used for it
+fn entry_key(entry: &ChartEntry) -> String {
+    entry.title_id().map_or_else(|| entry.id.clone(), |id| id.to_string())
+}
+
+/// Records snapshots of charts and reports what changed between them
+///
+/// ```no_run
+/// use imdb_api::{Endpoint, ImdbClient};
+/// use imdb_api::tracker::ChartTracker;
+///
+/// # async fn run() -> imdb_api::Result<()> {
+/// let tracker = ChartTracker::in_dir(ImdbClient::new(), "charts")?;
+/// if let Some(diff) = tracker.update(Endpoint::PopularMovies).await? {
+///     for m in diff.biggest_movers(5) {
+///         println!("{} {:+} to #{}", m.entry.id, m.rank_change, m.entry.rank);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChartTracker {
+    client: ImdbClient,
+    store: Arc<dyn ChartStore>,
+}
+
+impl ChartTracker {
+    /// Create a tracker keeping its history in `store`
+    pub fn new(client: ImdbClient, store: impl ChartStore + 'static) -> Self {
+        Self {
+            client,
+            store: Arc::new(store),
+        }
+    }
+
+    /// Create a tracker keeping its history as JSON files in `dir`
+    pub fn in_dir(client: ImdbClient, dir: impl Into<PathBuf>) -> Result<Self> {
+        Ok(Self::new(client, JsonChartStore::new(dir)?))
+    }
+
+    /// Fetch `chart` and add it to its history
+    pub async fn snapshot(&self, chart: Endpoint) -> Result<ChartSnapshot> {
+        let path = chart_path(chart)?;
+        let snapshot = ChartSnapshot {
+            path: path.to_string(),
+            taken_at: Utc::now(),
+            chart: self.client.get_model(path, "").await?,
+        };
+        self.store.save(&snapshot)?;
+        Ok(snapshot)
+    }
+
+    /// Every recorded snapshot of `chart`, oldest first
+    pub fn history(&self, chart: Endpoint) -> Result<Vec<ChartSnapshot>> {
+        self.store.load(chart_path(chart)?)
+    }
+
+    /// The most recent snapshot of `chart`
+    pub fn latest(&self, chart: Endpoint) -> Result<Option<ChartSnapshot>> {
+        Ok(self.history(chart)?.pop())
+    }
+
+    /// Take a snapshot of `chart` and compare it with the previous one
+    ///
+    /// Returns `None` on the first snapshot, as there is nothing to compare.
+    pub async fn update(&self, chart: Endpoint) -> Result<Option<ChartDiff>> {
+        let previous = self.latest(chart)?;
+        let current = self.snapshot(chart).await?;
+        Ok(previous.map(|previous| ChartDiff::between(&previous, &current)))
+    }
+
+    /// Compare the latest snapshot of `chart` with the last one taken at or before `since`
+    ///
+    /// Returns `None` if no snapshot is old enough.
+    pub fn diff_since(&self, chart: Endpoint, since: DateTime<Utc>) -> Result<Option<ChartDiff>> {
+        let history = self.history(chart)?;
+        let (Some(latest), Some(old)) = (
+            history.last(),
+            history.iter().rev().find(|s| s.taken_at <= since),
+        ) else {
+            return Ok(None);
+        };
+        Ok(Some(ChartDiff::between(old, latest)))
+    }
+}
+
+fn chart_path(chart: Endpoint) -> Result<&'static str> {
+    match chart.kind() {
+        EndpointKind::Chart => Ok(chart.path()),
+        _ => Err(ImdbApiError::NotAChart(chart)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+    use chrono::TimeZone;
+    use futures::future::BoxFuture;
+    use serde_json::json;
+
+    /// The tracker is only read from stored snapshots here
+    #[derive(Debug)]
+    struct Offline;
+
+    impl HttpTransport for Offline {
+        fn send(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            Box::pin(async { Err(ImdbApiError::transport("offline", false)) })
+        }
+    }
+
+    fn snapshot(day: u32, ids: &[&str]) -> ChartSnapshot {
+        let ranks: Vec<_> = ids.iter().map(|id| json!({ "id": format!("/title/{}/", id) })).collect();
+        ChartSnapshot {
+            path: Endpoint::PopularMovies.path().to_string(),
+            taken_at: Utc.with_ymd_and_hms(2024, 1, day, 9, 0, 0).unwrap(),
+            chart: serde_json::from_value(json!({ "ranks": ranks })).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_diff_between_snapshots() {
+        let old = snapshot(1, &["tt0000001", "tt0000002", "tt0000003", "tt0000004", "tt0000005"]);
+        let new = snapshot(8, &["tt0000005", "tt0000001", "tt0000009", "tt0000002", "tt0000004"]);
+        let diff = ChartDiff::between(&old, &new);
+
+        let ids = |entries: &[ChartEntry]| entries.iter().map(entry_key).collect::<Vec<_>>();
+        assert_eq!(ids(&diff.new_entries), ["tt0000009"]);
+        assert_eq!(ids(&diff.dropped_entries), ["tt0000003"]);
+
+        let moves: Vec<_> = diff.movers.iter().map(|m| (entry_key(&m.entry), m.rank_change)).collect();
+        assert_eq!(
+            moves,
+            [
+                ("tt0000005".to_string(), 4),
+                ("tt0000002".to_string(), -2),
+                ("tt0000001".to_string(), -1),
+                ("tt0000004".to_string(), -1),
+            ]
+        );
+        assert_eq!(diff.biggest_movers(1).len(), 1);
+        assert!(ChartDiff::between(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn test_json_store_keeps_history_in_order() {
+        let dir = std::env::temp_dir().join(format!("imdb-api-charts-{}", fastrand::u64(..)));
+        let store = JsonChartStore::new(&dir).unwrap();
+        let path = Endpoint::PopularMovies.path();
+        assert!(store.load(path).unwrap().is_empty());
+
+        store.save(&snapshot(8, &["tt0000002"])).unwrap();
+        store.save(&snapshot(1, &["tt0000001"])).unwrap();
+
+        let history = JsonChartStore::new(&dir).unwrap().load(path).unwrap();
+        assert_eq!(history, [snapshot(1, &["tt0000001"]), snapshot(8, &["tt0000002"])]);
+        assert!(store.load(Endpoint::TopMovies.path()).unwrap().is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_diff_since() {
+        let store = MemoryChartStore::new();
+        for (day, ids) in [(1, &["tt0000001", "tt0000002"]), (4, &["tt0000002", "tt0000001"]), (8, &["tt0000003", "tt0000001"])] {
+            store.save(&snapshot(day, ids)).unwrap();
+        }
+        let client = ImdbClient::builder().transport(Offline).build().unwrap();
+        let tracker = ChartTracker::new(client, store);
+
+        let since = Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+        let diff = tracker.diff_since(Endpoint::PopularMovies, since).unwrap().unwrap();
+        assert_eq!(diff.from, snapshot(4, &[]).taken_at);
+        assert_eq!(entry_key(&diff.new_entries[0]), "tt0000003");
+
+        let too_early = Utc.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
+        assert!(tracker.diff_since(Endpoint::PopularMovies, too_early).unwrap().is_none());
+        assert!(matches!(tracker.history(Endpoint::Title), Err(ImdbApiError::NotAChart(_))));
+    }
+}
@@ -0,0 +1,390 @@
+//! Pluggable HTTP transport
+//!
+//! Every request of [`ImdbClient`](crate::ImdbClient) goes through an
+//! [`HttpTransport`]. The default, `ReqwestTransport`, uses `reqwest` and is
+//! available with the `reqwest` feature (on by default); another HTTP stack, a
+//! mock or a recorder can be plugged in with
+//! [`ImdbClientBuilder::transport`](crate::ImdbClientBuilder::transport).
+//!
+//! The client waits between retries and for the rate limiter through a
+//! [`Sleep`]. The default, [`TokioSleep`], needs a Tokio runtime with the time
+//! driver enabled; a client driven by another runtime must be given its own
+//! with [`ImdbClientBuilder::sleep`](crate::ImdbClientBuilder::sleep).
+
+use crate::error::Result;
+use futures::future::BoxFuture;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+
+/// HTTP method of an [`HttpRequest`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A request to send, with its headers in the order they were added
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    /// A `GET` request without headers
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::Get, url)
+    }
+
+    /// A `POST` request without headers or body
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::Post, url)
+    }
+
+    fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Add a header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set the body
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The first value of a header, matched case-insensitively
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// A response, read in full
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// A response without headers
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// The first value of a header, matched case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Whether the status is `2xx`
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Whether the status is `3xx`
+    pub fn is_redirection(&self) -> bool {
+        (300..400).contains(&self.status)
+    }
+
+    /// The body as text, with invalid UTF-8 replaced
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// The body parsed as JSON
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Sends HTTP requests for an [`ImdbClient`](crate::ImdbClient)
+///
+/// Implementations only fail when no response could be obtained; error
+/// statuses are returned as responses. Failures worth retrying, such as
+/// connection errors and timeouts, should be reported with
+/// [`ImdbApiError::transport`](crate::ImdbApiError::transport) and `retryable`
+/// set. Retries, rate limiting and signing are handled by the client.
+pub trait HttpTransport: Send + Sync + fmt::Debug {
+    /// Send `request` and read the whole response
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// Waits for a delay on the runtime driving the client
+pub trait Sleep: Send + Sync + fmt::Debug {
+    /// A future completing after `duration`
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// The default [`Sleep`], using the Tokio timer
+///
+/// Sleeping panics outside a Tokio runtime with the time driver enabled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleep;
+
+impl Sleep for TokioSleep {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// The default transport, backed by a `reqwest::Client`
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let method = match request.method {
+                Method::Get => reqwest::Method::GET,
+                Method::Post => reqwest::Method::POST,
+            };
+            let mut builder = self.client.request(method, &request.url);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// Transport answering from a closure, for tests
+///
+/// Credential requests are answered with `AKID` / `secret` / `token-N`, where
+/// `N` counts the credential requests, unless other credentials are set with
+/// [`credentials`](Self::credentials). Every request is recorded.
+///
+/// ```
+/// use imdb_api::mock::StubTransport;
+/// use imdb_api::{HttpResponse, ImdbClient};
+///
+/// let transport = StubTransport::new(|_request| HttpResponse::new(404, "not found"));
+/// let client = ImdbClient::builder().transport(transport).build()?;
+/// # Ok::<(), imdb_api::ImdbApiError>(())
+/// ```
+#[cfg(any(test, feature = "test-util"))]
+pub struct StubTransport {
+    respond: Box<dyn Fn(HttpRequest) -> BoxFuture<'static, Result<HttpResponse>> + Send + Sync>,
+    credentials: Option<serde_json::Value>,
+    requests: std::sync::Mutex<Vec<HttpRequest>>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl StubTransport {
+    /// Answer every request but credential requests with `respond`
+    pub fn new(respond: impl Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static) -> Self {
+        Self::new_async(move |request| {
+            let response = respond(&request);
+            Box::pin(async move { Ok(response) })
+        })
+    }
+
+    /// Like [`new`](Self::new), with a responder that may wait or fail
+    pub fn new_async(
+        respond: impl Fn(HttpRequest) -> BoxFuture<'static, Result<HttpResponse>> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            respond: Box::new(respond),
+            credentials: None,
+            requests: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Issue the credentials `resource` instead of the default ones
+    pub fn credentials(mut self, resource: serde_json::Value) -> Self {
+        self.credentials = Some(resource);
+        self
+    }
+
+    /// Every request received so far, credential requests included
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl HttpTransport for StubTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        let issued = {
+            let mut requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+            requests.push(request.clone());
+            requests.iter().filter(|r| r.url.contains("/authentication/")).count()
+        };
+        if !request.url.contains("/authentication/") {
+            return (self.respond)(request);
+        }
+
+        let resource = self.credentials.clone().unwrap_or_else(|| {
+            serde_json::json!({
+                "accessKeyId": "AKID",
+                "secretAccessKey": "secret",
+                "sessionToken": format!("token-{}", issued),
+            })
+        });
+        let response = HttpResponse::new(200, serde_json::json!({ "resource": resource }).to_string());
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl fmt::Debug for StubTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StubTransport")
+            .field("requests", &self.requests.lock().map(|r| r.len()).unwrap_or_default())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ImdbClient;
+    use crate::rate_limit::RateLimit;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    /// Answers title requests with `statuses` in turn, from the end
+    fn title_stub(statuses: Vec<u16>) -> StubTransport {
+        let statuses = Mutex::new(statuses);
+        StubTransport::new(move |_| {
+            let status = statuses.lock().unwrap().pop().unwrap_or(200);
+            HttpResponse::new(status, json!({ "resource": { "title": "Se7en" } }).to_string())
+        })
+    }
+
+    #[tokio::test]
+    async fn test_client_goes_through_transport() {
+        let transport = Arc::new(title_stub(vec![200, 401]));
+        let client = ImdbClient::builder()
+            .base_uri("http://imdb.test")
+            .rate_limit(RateLimit::unlimited())
+            .shared_transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let title = client.get_title("tt0114369").await.unwrap();
+        assert_eq!(title["title"], "Se7en");
+
+        // The rejected signature triggers a credentials refresh and one retry.
+        let requests = transport.requests();
+        let methods: Vec<_> = requests.iter().map(|r| r.method).collect();
+        assert_eq!(methods, [Method::Post, Method::Get, Method::Post, Method::Get]);
+        assert_eq!(requests[1].url, "http://imdb.test/title/tt0114369/auxiliary");
+        assert_eq!(requests[1].header_value("X-Amz-Security-Token"), Some("token-1"));
+        assert_eq!(requests[3].header_value("x-amz-security-token"), Some("token-2"));
+        assert!(requests[3].header_value("x-amzn-authorization").is_some());
+    }
+
+    /// Records the delays instead of waiting
+    #[derive(Debug, Default)]
+    struct RecordingSleep {
+        delays: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl Sleep for RecordingSleep {
+        fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+            self.delays.lock().unwrap().push(duration);
+            Box::pin(async {})
+        }
+    }
+
+    #[test]
+    fn test_client_runs_without_tokio() {
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let client = ImdbClient::builder()
+            .base_uri("http://imdb.test")
+            .rate_limit(RateLimit {
+                requests_per_second: 1.0,
+                burst: 1,
+                max_concurrency: 1,
+            })
+            .sleep(RecordingSleep {
+                delays: delays.clone(),
+            })
+            .transport(title_stub(vec![200, 503]))
+            .build()
+            .unwrap();
+
+        let title = futures::executor::block_on(client.get_title("tt0114369")).unwrap();
+        assert_eq!(title["title"], "Se7en");
+
+        // The rate limiter and the retry after the 503 both went through the hook.
+        let delays = delays.lock().unwrap();
+        assert!(delays.len() >= 2, "{:?}", delays);
+    }
+}
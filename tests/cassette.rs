@@ -0,0 +1,113 @@
+//! Client tests replayed from recorded cassettes, without network access
+
+use imdb_api::cassette::{CassetteMode, CassetteTransport};
+use imdb_api::models::SearchResult;
+use imdb_api::{
+    Endpoint, ImdbApiError, ImdbClient, OptionalExt, RateLimit, RetryPolicy,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn cassette(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/cassettes").join(name)
+}
+
+fn replay(name: &str) -> ImdbClient {
+    let transport = CassetteTransport::replay(cassette(name)).unwrap();
+    assert_eq!(transport.mode(), CassetteMode::Replay);
+
+    ImdbClient::builder()
+        .rate_limit(RateLimit::unlimited())
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        })
+        .transport(transport)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_replay_typed_title_and_ratings() {
+    let client = replay("shawshank.json");
+
+    let title = client.get_title_typed("tt0111161").await.unwrap();
+    assert_eq!(title.title, "The Shawshank Redemption");
+    assert_eq!(title.year, Some(1994));
+
+    let ratings = client.get_title_ratings_typed("tt0111161").await.unwrap();
+    assert_eq!(ratings.rating, Some(9.3));
+}
+
+#[tokio::test]
+async fn test_replay_not_found_and_retry() {
+    let client = replay("shawshank.json");
+
+    assert!(client.get_title_opt("tt0000001").await.unwrap().is_none());
+    let err = client.get_title_ratings("tt0000001").await.unwrap_err();
+    assert!(matches!(err, ImdbApiError::TransportError { retryable: false, .. }));
+
+    // The cassette answers 503 first; the client retries and gets the title.
+    let title = client.get_title_typed("tt0068646").await.optional().unwrap();
+    assert_eq!(title.unwrap().title, "The Godfather");
+}
+
+#[tokio::test]
+async fn test_replay_search_chart_and_website() {
+    let client = replay("shawshank.json");
+
+    let results = client.search_results("The Shawshank Redemption").await.unwrap();
+    assert!(matches!(&results[0], SearchResult::Title(t) if t.id.to_string() == "tt0111161"));
+    assert!(matches!(&results[1], SearchResult::Name(n) if n.name == "Morgan Freeman"));
+
+    let chart = client.get_popular_movies_typed().await.unwrap();
+    assert_eq!(chart.ranks.len(), 3);
+    assert_eq!(client.get(Endpoint::PopularMovies, "").await.unwrap()["ranks"][0]["currentRank"], 1);
+
+    assert!(client.title_exists("tt0111161").await.unwrap());
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test]
+async fn test_record_then_replay_without_secrets() {
+    let path = std::env::temp_dir()
+        .join(format!("imdb-api-cassette-{}", fastrand::u64(..)))
+        .join("se7en.json");
+
+    let recorder = ImdbClient::builder()
+        .rate_limit(RateLimit::unlimited())
+        .transport(CassetteTransport::record(&path, fake_imdb()))
+        .build()
+        .unwrap();
+    assert_eq!(recorder.get_title_typed("tt0114369").await.unwrap().title, "Se7en");
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    for secret in ["AKIDSECRET", "very-secret", "session-secret", "x-amzn-authorization", "session=secret"] {
+        assert!(!text.to_lowercase().contains(&secret.to_lowercase()), "{} was recorded", secret);
+    }
+    assert_eq!(imdb_api::cassette::Cassette::load(&path).unwrap().interactions.len(), 2);
+
+    let client = ImdbClient::builder()
+        .transport(CassetteTransport::replay(&path).unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(client.get_title_typed("tt0114369").await.unwrap().year, Some(1995));
+    assert_eq!(client.get_title_typed("tt0114369").await.unwrap().year, Some(1995));
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+/// Stands in for the network while recording
+#[cfg(feature = "test-util")]
+fn fake_imdb() -> imdb_api::mock::StubTransport {
+    imdb_api::mock::StubTransport::new(|_| {
+        let body = r#"{"resource":{"id":"/title/tt0114369/","title":"Se7en","year":1995}}"#;
+        imdb_api::HttpResponse::new(200, body).with_header("set-cookie", "session=secret")
+    })
+    .credentials(serde_json::json!({
+        "accessKeyId": "AKIDSECRET",
+        "secretAccessKey": "very-secret",
+        "sessionToken": "session-secret",
+        "expirationTimeStamp": "2000-01-01T00:00:00Z",
+    }))
+}
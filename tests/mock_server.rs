@@ -0,0 +1,151 @@
+//! End-to-end client tests against the bundled mock server (`test-util` feature)
+
+use imdb_api::mock::MockServer;
+use imdb_api::models::SearchResult;
+use imdb_api::{ImdbApiError, ImdbClient, RateLimit, RetryPolicy};
+use serde_json::json;
+use std::time::Duration;
+
+fn client(server: &MockServer) -> ImdbClient {
+    server
+        .client_builder()
+        .rate_limit(RateLimit::unlimited())
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        })
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_signed_requests_are_accepted() {
+    let server = MockServer::start().await.unwrap();
+    server.add_resource("/title/tt0111161/auxiliary", json!({ "title": "The Shawshank Redemption", "year": 1994 }));
+    server.add_resource("/name/nm0000151/fulldetails", json!({ "name": "Morgan Freeman" }));
+    server.add_resource("/chart/top", json!({ "chart": [{ "id": "/title/tt0111161/", "chartRating": 9.3 }] }));
+    let client = client(&server);
+
+    assert_eq!(client.get_title_typed("tt0111161").await.unwrap().year, Some(1994));
+    assert_eq!(client.get_name("nm0000151").await.unwrap()["name"], "Morgan Freeman");
+    assert_eq!(client.get_top_movies_typed().await.unwrap().ranks[0].rank, 1);
+    assert_eq!(server.credentials_issued(), 1);
+}
+
+#[tokio::test]
+async fn test_missing_resource_is_not_found() {
+    let server = MockServer::start().await.unwrap();
+    let client = client(&server);
+
+    let err = client.get_title_typed("tt0000001").await.unwrap_err();
+    assert!(matches!(err, ImdbApiError::NotFound { status: 404, .. }));
+    assert!(client.get_title_opt("tt0000001").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_transient_errors_are_retried() {
+    let server = MockServer::start().await.unwrap();
+    server.add_resource("/title/tt0068646/auxiliary", json!({ "title": "The Godfather" }));
+    server.fail_next("/title/tt0068646/auxiliary", 503, None);
+    server.fail_next("/title/tt0068646/auxiliary", 429, Some(0));
+    let client = client(&server);
+
+    assert_eq!(client.get_title_typed("tt0068646").await.unwrap().title, "The Godfather");
+    let statuses: Vec<u16> = server.requests().iter().skip(1).map(|r| r.status).collect();
+    assert_eq!(statuses, [503, 429, 200]);
+}
+
+#[tokio::test]
+async fn test_retries_give_up() {
+    let server = MockServer::start().await.unwrap();
+    let client = server
+        .client_builder()
+        .rate_limit(RateLimit::unlimited())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    server.fail_next("/title/tt0068646/auxiliary", 500, None);
+
+    let err = client.get_title("tt0068646").await.unwrap_err();
+    assert!(matches!(err, ImdbApiError::ServerError { status: 500, .. }));
+}
+
+#[tokio::test]
+async fn test_revoked_credentials_are_refreshed() {
+    let server = MockServer::start().await.unwrap();
+    server.add_resource("/title/tt0111161/ratings", json!({ "rating": 9.3 }));
+    let client = client(&server);
+
+    client.get_title_ratings("tt0111161").await.unwrap();
+    server.revoke_credentials();
+    assert_eq!(client.get_title_ratings_typed("tt0111161").await.unwrap().rating, Some(9.3));
+
+    assert_eq!(server.credentials_issued(), 2);
+    let statuses: Vec<u16> = server.requests().iter().map(|r| r.status).collect();
+    assert_eq!(statuses, [200, 200, 403, 200, 200]);
+}
+
+#[tokio::test]
+async fn test_failed_credentials_are_reported() {
+    let server = MockServer::start().await.unwrap();
+    server.fail_next("/authentication/credentials/temporary/ios82", 401, None);
+    let client = client(&server);
+
+    let err = client.get_title("tt0111161").await.unwrap_err();
+    assert!(matches!(err, ImdbApiError::Unauthorized { status: 401, .. }));
+}
+
+#[tokio::test]
+async fn test_search_and_title_exists() {
+    let server = MockServer::start().await.unwrap();
+    server.add_suggestions(
+        "the_matrix",
+        json!([{ "l": "The Matrix", "id": "tt0133093", "q": "feature", "y": 1999 }]),
+    );
+    server.add_suggestions(
+        "spider-man",
+        json!([{ "l": "Spider-Man", "id": "tt0145487", "q": "feature", "y": 2002 }]),
+    );
+    server.add_web_title("tt0133093");
+    let client = client(&server);
+
+    let results = client.search_results("The Matrix").await.unwrap();
+    assert!(matches!(&results[..], [SearchResult::Title(t)] if t.year == Some(1999)));
+    assert!(client.search_results("Unknown").await.unwrap().is_empty());
+    let results = client.search_results("Spider-Man").await.unwrap();
+    assert!(matches!(&results[..], [SearchResult::Title(t)] if t.year == Some(2002)));
+
+    assert!(client.title_exists("tt0133093").await.unwrap());
+    assert!(!client.title_exists("tt0000001").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_resolve_title_with_tag_words() {
+    let server = MockServer::start().await.unwrap();
+    server.add_suggestions(
+        "uncut_gems",
+        json!([{ "l": "Uncut Gems", "id": "tt5727208", "q": "feature", "y": 2019 }]),
+    );
+    let client = client(&server);
+
+    let matches = client.resolve_title("Uncut Gems (2019)", None, None).await.unwrap();
+    assert_eq!(matches[0].id.to_string(), "tt5727208");
+    assert!(matches[0].confidence > 0.99);
+}
+
+#[tokio::test]
+async fn test_bad_signatures_are_rejected() {
+    let server = MockServer::start().await.unwrap();
+    server.add_resource("/title/tt0111161/auxiliary", json!({ "title": "The Shawshank Redemption" }));
+    client(&server).get_title("tt0111161").await.unwrap();
+
+    let response = reqwest::Client::new()
+        .get(format!("{}/title/tt0111161/auxiliary", server.uri()))
+        .header("x-amz-date", "Mon, 01 Jan 2024 00:00:00 GMT")
+        .header("x-amz-security-token", "mock-session-1")
+        .header("x-amzn-authorization", "AWS3 AWSAccessKeyId=X,Signature=Zm9yZ2Vk")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 403);
+}